@@ -0,0 +1,98 @@
+//! Compares the iterator methods of `Either` against the bare inner iterator.
+//!
+//! The contained iterator is a `Chain`, whose internal iteration (`fold`,
+//! `try_fold`, ...) is considerably faster than repeatedly calling `next()`.
+//! Each benchmark should report the same timing for both columns, as long as
+//! `IntoIter` forwards the method to the contained iterator.
+//!
+//! Run with `cargo bench`, or `cargo +nightly bench --features try_fold`
+//! to include the `try_fold` based methods.
+
+use std::hint::black_box;
+use std::iter::Chain;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use either::Either::{self, Left, Right};
+
+const LEN: u64 = 1 << 16;
+
+type Inner = Chain<Range<u64>, Range<u64>>;
+
+fn bare() -> Inner {
+    (0..black_box(LEN)).chain(0..black_box(LEN))
+}
+
+fn either() -> Either<Inner, Range<u64>> {
+    if black_box(true) {
+        Left(bare())
+    } else {
+        Right(0..0)
+    }
+}
+
+/// Returns the minimum duration of one call to `f`.
+fn measure<T>(mut f: impl FnMut() -> T) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..64 {
+        let start = Instant::now();
+        black_box(f());
+        best = best.min(start.elapsed());
+    }
+    best
+}
+
+fn bench<T>(
+    name: &str,
+    mut bare: impl FnMut(Inner) -> T,
+    mut either: impl FnMut(Either<Inner, Range<u64>>) -> T,
+) {
+    let lhs = measure(|| bare(self::bare()));
+    let rhs = measure(|| either(self::either()));
+    let ratio = rhs.as_secs_f64() / lhs.as_secs_f64();
+    println!("{name:<16} bare: {lhs:>10.2?}  either: {rhs:>10.2?}  ratio: {ratio:.2}");
+}
+
+fn main() {
+    bench(
+        "fold",
+        |x| x.fold(0, u64::wrapping_add),
+        |x| x.into_iter().fold(0, u64::wrapping_add),
+    );
+    bench("sum", |x| x.sum::<u64>(), |x| x.into_iter().sum::<u64>());
+    bench("count", |x| x.count(), |x| x.into_iter().count());
+    bench(
+        "nth",
+        |mut x| x.nth(black_box(LEN as usize)),
+        |x| x.into_iter().nth(black_box(LEN as usize)),
+    );
+    bench(
+        "find",
+        |mut x| x.find(|&x| x == LEN - 1),
+        |x| x.into_iter().find(|&x| x == LEN - 1),
+    );
+    bench(
+        "position",
+        |mut x| x.position(|x| x == LEN - 1),
+        |x| x.into_iter().position(|x| x == LEN - 1),
+    );
+    bench(
+        "rfold",
+        |x| x.rfold(0, u64::wrapping_add),
+        |x| x.into_iter().rfold(0, u64::wrapping_add),
+    );
+    bench("max", |x| x.max(), |x| x.into_iter().max());
+    bench(
+        "try_for_each",
+        |mut x| x.try_for_each(|x| if x == LEN - 1 { Err(x) } else { Ok(()) }),
+        |x| {
+            x.into_iter()
+                .try_for_each(|x| if x == LEN - 1 { Err(x) } else { Ok(()) })
+        },
+    );
+    bench(
+        "sum_option",
+        |x| x.map(Some).sum::<Option<u64>>(),
+        |x| x.into_iter().map(Some).sum::<Option<u64>>(),
+    );
+}
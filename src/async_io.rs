@@ -0,0 +1,245 @@
+//! This module implements the asynchronous I/O traits for [`Either`], if and
+//! only if, both `L` and `R` implement the corresponding trait:
+//!
+//! - with the `tokio` feature, the `tokio::io` traits `AsyncRead`,
+//!   `AsyncBufRead`, `AsyncWrite` and `AsyncSeek`
+//! - with the `futures-io` feature, the `futures_io` traits of the same names
+//!
+//! The contained value is projected with [`Either::as_pin_mut`], so `L` and
+//! `R` are not required to implement [`Unpin`].
+//!
+//! [`Either`]: crate::Either
+//! [`Either::as_pin_mut`]: crate::Either::as_pin_mut
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "tokio")] {
+//! # use either::Either::{self, Left, Right};
+//! use futures::executor::block_on;
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt, Empty};
+//!
+//! let mut x: Either<&[u8], Empty> = Left(b"hello");
+//! let mut buf = String::new();
+//! block_on(x.read_to_string(&mut buf)).unwrap();
+//! assert_eq!(buf, "hello");
+//!
+//! let mut y: Either<Empty, Vec<u8>> = Right(Vec::new());
+//! block_on(y.write_all(b"world")).unwrap();
+//! assert_eq!(y.right(), Some(b"world".to_vec()));
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "futures-io")] {
+//! # use either::Either::{self, Left, Right};
+//! use futures::executor::block_on;
+//! use futures::io::{AsyncBufReadExt, AsyncWriteExt, Cursor, Empty, Sink};
+//!
+//! let mut x: Either<Cursor<&[u8]>, Empty> = Left(Cursor::new(b"hello\nworld"));
+//! let mut buf = String::new();
+//! block_on(x.read_line(&mut buf)).unwrap();
+//! assert_eq!(buf, "hello\n");
+//!
+//! let mut y: Either<Sink, Vec<u8>> = Right(Vec::new());
+//! block_on(y.write_all(b"world")).unwrap();
+//! assert_eq!(y.right(), Some(b"world".to_vec()));
+//! # }
+//! ```
+
+#[cfg(feature = "tokio")]
+mod tokio_io {
+    use std::io::{self, IoSlice, SeekFrom};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+    use crate::Either;
+
+    impl<L, R> AsyncRead for Either<L, R>
+    where
+        L: AsyncRead,
+        R: AsyncRead,
+    {
+        #[inline]
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            for_both!(self.as_pin_mut(), x => x.poll_read(cx, buf))
+        }
+    }
+
+    impl<L, R> AsyncBufRead for Either<L, R>
+    where
+        L: AsyncBufRead,
+        R: AsyncBufRead,
+    {
+        #[inline]
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            for_both!(self.as_pin_mut(), x => x.poll_fill_buf(cx))
+        }
+
+        #[inline]
+        fn consume(self: Pin<&mut Self>, amt: usize) {
+            for_both!(self.as_pin_mut(), x => x.consume(amt))
+        }
+    }
+
+    impl<L, R> AsyncWrite for Either<L, R>
+    where
+        L: AsyncWrite,
+        R: AsyncWrite,
+    {
+        #[inline]
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_write(cx, buf))
+        }
+
+        #[inline]
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            for_both!(self.as_pin_mut(), x => x.poll_flush(cx))
+        }
+
+        #[inline]
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            for_both!(self.as_pin_mut(), x => x.poll_shutdown(cx))
+        }
+
+        #[inline]
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[IoSlice<'_>],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_write_vectored(cx, bufs))
+        }
+
+        #[inline]
+        fn is_write_vectored(&self) -> bool {
+            for_both!(self, x => x.is_write_vectored())
+        }
+    }
+
+    impl<L, R> AsyncSeek for Either<L, R>
+    where
+        L: AsyncSeek,
+        R: AsyncSeek,
+    {
+        #[inline]
+        fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+            for_both!(self.as_pin_mut(), x => x.start_seek(position))
+        }
+
+        #[inline]
+        fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+            for_both!(self.as_pin_mut(), x => x.poll_complete(cx))
+        }
+    }
+}
+
+#[cfg(feature = "futures-io")]
+mod futures_io {
+    use std::io::{self, IoSlice, IoSliceMut, SeekFrom};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite};
+
+    use crate::Either;
+
+    impl<L, R> AsyncRead for Either<L, R>
+    where
+        L: AsyncRead,
+        R: AsyncRead,
+    {
+        #[inline]
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_read(cx, buf))
+        }
+
+        #[inline]
+        fn poll_read_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &mut [IoSliceMut<'_>],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_read_vectored(cx, bufs))
+        }
+    }
+
+    impl<L, R> AsyncBufRead for Either<L, R>
+    where
+        L: AsyncBufRead,
+        R: AsyncBufRead,
+    {
+        #[inline]
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            for_both!(self.as_pin_mut(), x => x.poll_fill_buf(cx))
+        }
+
+        #[inline]
+        fn consume(self: Pin<&mut Self>, amt: usize) {
+            for_both!(self.as_pin_mut(), x => x.consume(amt))
+        }
+    }
+
+    impl<L, R> AsyncWrite for Either<L, R>
+    where
+        L: AsyncWrite,
+        R: AsyncWrite,
+    {
+        #[inline]
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_write(cx, buf))
+        }
+
+        #[inline]
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[IoSlice<'_>],
+        ) -> Poll<io::Result<usize>> {
+            for_both!(self.as_pin_mut(), x => x.poll_write_vectored(cx, bufs))
+        }
+
+        #[inline]
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            for_both!(self.as_pin_mut(), x => x.poll_flush(cx))
+        }
+
+        #[inline]
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            for_both!(self.as_pin_mut(), x => x.poll_close(cx))
+        }
+    }
+
+    impl<L, R> AsyncSeek for Either<L, R>
+    where
+        L: AsyncSeek,
+        R: AsyncSeek,
+    {
+        #[inline]
+        fn poll_seek(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: SeekFrom,
+        ) -> Poll<io::Result<u64>> {
+            for_both!(self.as_pin_mut(), x => x.poll_seek(cx, pos))
+        }
+    }
+}
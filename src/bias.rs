@@ -0,0 +1,56 @@
+//! This module defines the type-level markers [`LeftBiased`] and
+//! [`RightBiased`], selecting a variant of [`Either`] when there is no value
+//! to take it from, e.g. when constructing the zero of a number.
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::bias::{Bias, LeftBiased, RightBiased};
+//!
+//! let x: Either<u8, &str> = LeftBiased::select(|| 0, || "zero");
+//! assert_eq!(x, Left(0));
+//!
+//! let x: Either<u8, &str> = RightBiased::select(|| 0, || "zero");
+//! assert_eq!(x, Right("zero"));
+//! ```
+
+use crate::Either::{self, Left, Right};
+
+/// The type-level selection of a variant of [`Either`], implemented by
+/// [`LeftBiased`] and [`RightBiased`].
+///
+/// See the [module-level documentation](self) for more details.
+pub trait Bias {
+    /// `true` if the [`Left`] variant is selected.
+    const IS_LEFT: bool;
+
+    /// Calls the function of the selected variant, returning the result
+    /// wrapped in that variant.
+    #[inline]
+    fn select<L, R, F, G>(left: F, right: G) -> Either<L, R>
+    where
+        F: FnOnce() -> L,
+        G: FnOnce() -> R,
+    {
+        if Self::IS_LEFT {
+            Left(left())
+        } else {
+            Right(right())
+        }
+    }
+}
+
+/// The marker selecting the [`Left`] variant.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LeftBiased;
+
+/// The marker selecting the [`Right`] variant.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RightBiased;
+
+impl Bias for LeftBiased {
+    const IS_LEFT: bool = true;
+}
+
+impl Bias for RightBiased {
+    const IS_LEFT: bool = false;
+}
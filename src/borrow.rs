@@ -1,5 +1,10 @@
 //! This module implements the [`Borrow`] trait for [`Either`], which allows to
 //! borrow slice, [`str`], [`CStr`], [`OsStr`] and [`Path`].
+//!
+//! NOTE: the derived [`Hash`] and [`Eq`] of [`Either`] include the variant, so
+//! they are not consistent with the borrowed view. Use the
+//! [`Transparent`](crate::key::Transparent) wrapper for map and set keys
+//! which are looked up by the borrowed view.
 
 use core::borrow::{Borrow, BorrowMut};
 use core::ffi::CStr;
@@ -9,7 +14,7 @@ use std::ffi::OsStr;
 #[cfg(feature = "std")]
 use std::path::Path;
 
-use crate::Either::{self, Left, Right};
+use crate::Either;
 
 impl<T, L, R> Borrow<[T]> for Either<L, R>
 where
@@ -17,10 +22,7 @@ where
     R: Borrow<[T]>,
 {
     fn borrow(&self) -> &[T] {
-        match self {
-            Left(x) => x.borrow(),
-            Right(x) => x.borrow(),
-        }
+        for_both!(self, x => x.borrow())
     }
 }
 
@@ -30,10 +32,7 @@ where
     R: BorrowMut<[T]>,
 {
     fn borrow_mut(&mut self) -> &mut [T] {
-        match self {
-            Left(x) => x.borrow_mut(),
-            Right(x) => x.borrow_mut(),
-        }
+        for_both!(self, x => x.borrow_mut())
     }
 }
 
@@ -43,10 +42,7 @@ where
     R: Borrow<str>,
 {
     fn borrow(&self) -> &str {
-        match self {
-            Left(x) => x.borrow(),
-            Right(x) => x.borrow(),
-        }
+        for_both!(self, x => x.borrow())
     }
 }
 
@@ -56,10 +52,7 @@ where
     R: BorrowMut<str>,
 {
     fn borrow_mut(&mut self) -> &mut str {
-        match self {
-            Left(x) => x.borrow_mut(),
-            Right(x) => x.borrow_mut(),
-        }
+        for_both!(self, x => x.borrow_mut())
     }
 }
 
@@ -69,10 +62,7 @@ where
     R: Borrow<CStr>,
 {
     fn borrow(&self) -> &CStr {
-        match self {
-            Left(x) => x.borrow(),
-            Right(x) => x.borrow(),
-        }
+        for_both!(self, x => x.borrow())
     }
 }
 
@@ -82,10 +72,7 @@ where
     R: BorrowMut<CStr>,
 {
     fn borrow_mut(&mut self) -> &mut CStr {
-        match self {
-            Left(x) => x.borrow_mut(),
-            Right(x) => x.borrow_mut(),
-        }
+        for_both!(self, x => x.borrow_mut())
     }
 }
 
@@ -96,10 +83,7 @@ where
     R: Borrow<OsStr>,
 {
     fn borrow(&self) -> &OsStr {
-        match self {
-            Left(x) => x.borrow(),
-            Right(x) => x.borrow(),
-        }
+        for_both!(self, x => x.borrow())
     }
 }
 
@@ -110,10 +94,7 @@ where
     R: BorrowMut<OsStr>,
 {
     fn borrow_mut(&mut self) -> &mut OsStr {
-        match self {
-            Left(x) => x.borrow_mut(),
-            Right(x) => x.borrow_mut(),
-        }
+        for_both!(self, x => x.borrow_mut())
     }
 }
 
@@ -124,10 +105,7 @@ where
     R: Borrow<Path>,
 {
     fn borrow(&self) -> &Path {
-        match self {
-            Left(x) => x.borrow(),
-            Right(x) => x.borrow(),
-        }
+        for_both!(self, x => x.borrow())
     }
 }
 
@@ -138,9 +116,6 @@ where
     R: BorrowMut<Path>,
 {
     fn borrow_mut(&mut self) -> &mut Path {
-        match self {
-            Left(x) => x.borrow_mut(),
-            Right(x) => x.borrow_mut(),
-        }
+        for_both!(self, x => x.borrow_mut())
     }
 }
@@ -0,0 +1,251 @@
+//! This module implements the calling of [`Either`] as a function, if and
+//! only if, both `L` and `R` implement the corresponding function trait with
+//! the same output type.
+//!
+//! [`Either::call`], [`Either::call_mut`] and [`Either::call_once`] take the
+//! arguments as a tuple, for 0 to 12 arguments. The traits [`Call`],
+//! [`CallMut`] and [`CallOnce`] describe the functions callable in this way,
+//! and are implemented for all closures, function items, function pointers
+//! and boxed functions of the corresponding [`Fn`], [`FnMut`] and [`FnOnce`]
+//! traits.
+//!
+//! With the nightly `fn_traits` feature, [`Either`] also implements [`Fn`],
+//! [`FnMut`] and [`FnOnce`] directly, so it can be called as `f(x, y)`.
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! fn double(x: i32) -> i32 {
+//!     x * 2
+//! }
+//!
+//! let offset = 10;
+//! let strategies: [Either<fn(i32) -> i32, Box<dyn Fn(i32) -> i32>>; 2] =
+//!     [Left(double), Right(Box::new(move |x| x + offset))];
+//!
+//! assert_eq!(strategies[0].call((4,)), 8);
+//! assert_eq!(strategies[1].call((4,)), 14);
+//!
+//! let mut count = 0;
+//! let mut f: Either<_, fn() -> usize> = Left(|| {
+//!     count += 1;
+//!     count
+//! });
+//! assert_eq!(f.call_mut(()), 1);
+//! assert_eq!(f.call_mut(()), 2);
+//!
+//! let name = String::from("either");
+//! let g: Either<_, fn(&str, usize) -> String> = Left(move |sep: &str, n: usize| name + &sep.repeat(n));
+//! assert_eq!(g.call_once(("!", 2)), "either!!");
+//! ```
+//!
+//! With the `fn_traits` feature:
+//!
+//! ```
+//! # #[cfg(feature = "fn_traits")] {
+//! # use either::Either::{self, Left, Right};
+//! let f: Either<fn(i32) -> i32, _> = Right(|x: i32| x + 1);
+//! assert_eq!(f(1), 2);
+//!
+//! let v: Vec<i32> = [1, 2].into_iter().map(&f).collect();
+//! assert_eq!(v, [2, 3]);
+//! # }
+//! ```
+
+use crate::Either;
+
+/// A function which can be called once with a tuple of arguments.
+///
+/// This trait is implemented for all [`FnOnce`] types of 0 to 12 arguments.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait CallOnce<Args> {
+    /// The return type of the function.
+    type Output;
+
+    /// Calls the function with the tuple of arguments.
+    fn call_once(self, args: Args) -> Self::Output;
+}
+
+/// A function which can be called by mutable reference with a tuple of
+/// arguments.
+///
+/// This trait is implemented for all [`FnMut`] types of 0 to 12 arguments.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait CallMut<Args>: CallOnce<Args> {
+    /// Calls the function with the tuple of arguments.
+    fn call_mut(&mut self, args: Args) -> Self::Output;
+}
+
+/// A function which can be called by shared reference with a tuple of
+/// arguments.
+///
+/// This trait is implemented for all [`Fn`] types of 0 to 12 arguments.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait Call<Args>: CallMut<Args> {
+    /// Calls the function with the tuple of arguments.
+    fn call(&self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_call {
+    ($($T:ident $arg:ident),*) => {
+        impl<Func, Out, $($T),*> CallOnce<($($T,)*)> for Func
+        where
+            Func: FnOnce($($T),*) -> Out,
+        {
+            type Output = Out;
+
+            #[inline]
+            fn call_once(self, ($($arg,)*): ($($T,)*)) -> Out {
+                self($($arg),*)
+            }
+        }
+
+        impl<Func, Out, $($T),*> CallMut<($($T,)*)> for Func
+        where
+            Func: FnMut($($T),*) -> Out,
+        {
+            #[inline]
+            fn call_mut(&mut self, ($($arg,)*): ($($T,)*)) -> Out {
+                self($($arg),*)
+            }
+        }
+
+        impl<Func, Out, $($T),*> Call<($($T,)*)> for Func
+        where
+            Func: Fn($($T),*) -> Out,
+        {
+            #[inline]
+            fn call(&self, ($($arg,)*): ($($T,)*)) -> Out {
+                self($($arg),*)
+            }
+        }
+    };
+}
+
+impl_call!();
+impl_call!(T1 a1);
+impl_call!(T1 a1, T2 a2);
+impl_call!(T1 a1, T2 a2, T3 a3);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7, T8 a8);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7, T8 a8, T9 a9);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7, T8 a8, T9 a9, T10 a10);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7, T8 a8, T9 a9, T10 a10, T11 a11);
+impl_call!(T1 a1, T2 a2, T3 a3, T4 a4, T5 a5, T6 a6, T7 a7, T8 a8, T9 a9, T10 a10, T11 a11, T12 a12);
+
+impl<L, R> Either<L, R> {
+    /// Calls the contained function by shared reference, with a tuple of
+    /// arguments.
+    ///
+    /// See the [`call`](crate::call) module for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let f: Either<fn(i32, i32) -> i32, fn(i32, i32) -> i32> = Right(i32::max);
+    /// assert_eq!(f.call((1, 2)), 2);
+    /// ```
+    #[inline]
+    pub fn call<Args>(&self, args: Args) -> L::Output
+    where
+        L: Call<Args>,
+        R: Call<Args, Output = L::Output>,
+    {
+        for_both!(self, f => f.call(args))
+    }
+
+    /// Calls the contained function by mutable reference, with a tuple of
+    /// arguments.
+    ///
+    /// See the [`call`](crate::call) module for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut items = Vec::new();
+    /// let mut f: Either<_, fn(i32)> = Left(|x| items.push(x));
+    /// f.call_mut((1,));
+    /// f.call_mut((2,));
+    /// drop(f);
+    /// assert_eq!(items, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn call_mut<Args>(&mut self, args: Args) -> L::Output
+    where
+        L: CallMut<Args>,
+        R: CallMut<Args, Output = L::Output>,
+    {
+        for_both!(self, f => f.call_mut(args))
+    }
+
+    /// Calls the contained function by value, with a tuple of arguments.
+    ///
+    /// See the [`call`](crate::call) module for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let items = vec![1, 2, 3];
+    /// let f: Either<fn() -> Vec<i32>, _> = Right(move || items);
+    /// assert_eq!(f.call_once(()), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn call_once<Args>(self, args: Args) -> L::Output
+    where
+        L: CallOnce<Args>,
+        R: CallOnce<Args, Output = L::Output>,
+    {
+        for_both!(self, f => f.call_once(args))
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<L, R, Args> FnOnce<Args> for Either<L, R>
+where
+    Args: core::marker::Tuple,
+    L: FnOnce<Args>,
+    R: FnOnce<Args, Output = L::Output>,
+{
+    type Output = L::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output {
+        for_both!(self, f => FnOnce::call_once(f, args))
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<L, R, Args> FnMut<Args> for Either<L, R>
+where
+    Args: core::marker::Tuple,
+    L: FnMut<Args>,
+    R: FnMut<Args, Output = L::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output {
+        for_both!(self, f => FnMut::call_mut(f, args))
+    }
+}
+
+#[cfg(feature = "fn_traits")]
+impl<L, R, Args> Fn<Args> for Either<L, R>
+where
+    Args: core::marker::Tuple,
+    L: Fn<Args>,
+    R: Fn<Args, Output = L::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output {
+        for_both!(self, f => Fn::call(f, args))
+    }
+}
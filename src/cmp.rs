@@ -0,0 +1,424 @@
+//! This module implements the orders of [`Either`] other than the derived
+//! one, which sorts every [`Left`] value before every [`Right`] value:
+//!
+//! - [`Either::cmp_right_first`] sorts every [`Right`] value first;
+//! - [`Either::cmp_by_value`] compares the contained values only, after
+//!   promoting the [`Left`] value by the [`Promote`] trait if the variants
+//!   differ, e.g. for `Either<i32, i64>`;
+//! - [`Either::cmp_by`] compares with the given functions, including the
+//!   one between the [`Left`] and [`Right`] values.
+//!
+//! The wrappers [`RightFirst`] and [`ByValue`] implement [`Ord`] by the
+//! first two, so they can be used as the keys of [`BTreeMap`] and the like.
+//!
+//! This module also implements the comparisons between [`Either`] and
+//! [`Result`], where [`Left`] corresponds to [`Ok`] and [`Right`] corresponds
+//! to [`Err`], and [`Either::left_eq`] and [`Either::right_eq`] compare with
+//! a value of either side.
+//!
+//! [`Left`]: crate::Either::Left
+//! [`Right`]: crate::Either::Right
+//! [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::cmp::{ByValue, RightFirst};
+//! use std::collections::BTreeSet;
+//!
+//! let items: [Either<i32, i64>; 4] = [Right(1 << 40), Left(7), Right(-3), Left(-8)];
+//!
+//! let mut sorted = items;
+//! sorted.sort();
+//! assert_eq!(sorted, [Left(-8), Left(7), Right(-3), Right(1 << 40)]);
+//!
+//! sorted.sort_by(Either::cmp_right_first);
+//! assert_eq!(sorted, [Right(-3), Right(1 << 40), Left(-8), Left(7)]);
+//!
+//! sorted.sort_by(Either::cmp_by_value);
+//! assert_eq!(sorted, [Left(-8), Right(-3), Left(7), Right(1 << 40)]);
+//!
+//! let set: BTreeSet<RightFirst<i32, i64>> = items.into_iter().map(RightFirst).collect();
+//! assert_eq!(set.first(), Some(&RightFirst(Right(-3))));
+//!
+//! let set: BTreeSet<ByValue<i32, i64>> = items.into_iter().map(ByValue).collect();
+//! assert_eq!(set.last(), Some(&ByValue(Right(1 << 40))));
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::Either::{self, Left, Right};
+use crate::ops::Promote;
+
+impl<L, R> Either<L, R> {
+    /// Compares `self` with `other` by the contained values, while every
+    /// [`Right`] value is less than every [`Left`] value.
+    ///
+    /// This is the reverse of the derived order between the variants, but
+    /// not between the values of the same variant.
+    ///
+    /// # Result
+    ///
+    /// | `self`     | `other`    | Output     |
+    /// | ---------- | ---------- | ---------- |
+    /// | `Left(a)`  | `Left(b)`  | `a.cmp(b)` |
+    /// | `Left(_)`  | `Right(_)` | `Greater`  |
+    /// | `Right(_)` | `Left(_)`  | `Less`     |
+    /// | `Right(a)` | `Right(b)` | `a.cmp(b)` |
+    ///
+    /// [`Left`]: crate::Either::Left
+    /// [`Right`]: crate::Either::Right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Either<u8, char> = Left(1);
+    /// assert_eq!(x.cmp_right_first(&Right('a')), Ordering::Greater);
+    /// assert_eq!(x.cmp_right_first(&Left(2)), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_right_first(&self, other: &Self) -> Ordering
+    where
+        L: Ord,
+        R: Ord,
+    {
+        self.cmp_by(other, L::cmp, R::cmp, |_, _| Ordering::Greater)
+    }
+
+    /// Compares `self` with `other` by the contained values only, promoting
+    /// the [`Left`] value into `R` if the variants differ.
+    ///
+    /// So `Left(x)` and `Right(y)` are equal if `x.promote()` equals `y`.
+    ///
+    /// This is a total order only if [`Promote`] preserves the order and is
+    /// injective, as documented on the trait. Since [`Promote::promote`]
+    /// takes the value by value, the [`Left`] value is cloned on each
+    /// comparison between different variants, which is cheap for the
+    /// primitive numeric types.
+    ///
+    /// # Result
+    ///
+    /// | `self`     | `other`    | Output                |
+    /// | ---------- | ---------- | --------------------- |
+    /// | `Left(a)`  | `Left(b)`  | `a.cmp(b)`            |
+    /// | `Left(a)`  | `Right(b)` | `a.promote().cmp(b)`  |
+    /// | `Right(a)` | `Left(b)`  | `a.cmp(&b.promote())` |
+    /// | `Right(a)` | `Right(b)` | `a.cmp(b)`            |
+    ///
+    /// [`Left`]: crate::Either::Left
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::cmp::Ordering;
+    ///
+    /// let x: Either<i32, i64> = Left(-1);
+    /// assert_eq!(x.cmp_by_value(&Right(-2)), Ordering::Greater);
+    /// assert_eq!(x.cmp_by_value(&Right(-1)), Ordering::Equal);
+    /// assert_eq!(x.cmp_by_value(&Left(0)), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_by_value(&self, other: &Self) -> Ordering
+    where
+        L: Ord + Promote<R> + Clone,
+        R: Ord,
+    {
+        self.cmp_by(other, L::cmp, R::cmp, |a, b| a.clone().promote().cmp(b))
+    }
+
+    /// Compares `self` with `other` by function `lcmp` if both are [`Left`],
+    /// by function `rcmp` if both are [`Right`], and by function `cross`
+    /// between the [`Left`] value and the [`Right`] value otherwise.
+    ///
+    /// # Result
+    ///
+    /// | `self`     | `other`    | Output                  |
+    /// | ---------- | ---------- | ----------------------- |
+    /// | `Left(a)`  | `Left(b)`  | `lcmp(a, b)`            |
+    /// | `Left(a)`  | `Right(b)` | `cross(a, b)`           |
+    /// | `Right(a)` | `Left(b)`  | `cross(b, a).reverse()` |
+    /// | `Right(a)` | `Right(b)` | `rcmp(a, b)`            |
+    ///
+    /// [`Left`]: crate::Either::Left
+    /// [`Right`]: crate::Either::Right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::cmp::Ordering;
+    ///
+    /// // Sort strings by length and numbers by value, with each string before
+    /// // the number equal to its length.
+    /// let mut items: Vec<Either<&str, usize>> = vec![Left("ccc"), Right(2), Right(1), Left("a")];
+    /// items.sort_by(|x, y| {
+    ///     x.cmp_by(
+    ///         y,
+    ///         |a, b| a.len().cmp(&b.len()),
+    ///         |a, b| a.cmp(b),
+    ///         |a, b| a.len().cmp(b).then(Ordering::Less),
+    ///     )
+    /// });
+    /// assert_eq!(items, [Left("a"), Right(1), Right(2), Left("ccc")]);
+    /// ```
+    #[inline]
+    pub fn cmp_by<F, G, H>(&self, other: &Self, lcmp: F, rcmp: G, cross: H) -> Ordering
+    where
+        F: FnOnce(&L, &L) -> Ordering,
+        G: FnOnce(&R, &R) -> Ordering,
+        H: FnOnce(&L, &R) -> Ordering,
+    {
+        match (self, other) {
+            (Left(a), Left(b)) => lcmp(a, b),
+            (Left(a), Right(b)) => cross(a, b),
+            (Right(a), Left(b)) => cross(b, a).reverse(),
+            (Right(a), Right(b)) => rcmp(a, b),
+        }
+    }
+
+    /// Returns `true` if `self` is [`Left`] and the contained value equals
+    /// `other`.
+    ///
+    /// NOTE: [`PartialEq<L>`] itself cannot be implemented for [`Either`],
+    /// since it would overlap with the derived [`PartialEq`] when `L` is an
+    /// [`Either`] too.
+    ///
+    /// [`Left`]: crate::Either::Left
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let x: Either<String, u8> = Left("either".to_owned());
+    /// assert!(x.left_eq("either"));
+    /// assert!(!x.right_eq(&0));
+    /// ```
+    #[inline]
+    pub fn left_eq<T>(&self, other: &T) -> bool
+    where
+        L: PartialEq<T>,
+        T: ?Sized,
+    {
+        matches!(self, Left(x) if x == other)
+    }
+
+    /// Returns `true` if `self` is [`Right`] and the contained value equals
+    /// `other`.
+    ///
+    /// See [`left_eq`](Either::left_eq) for more details.
+    ///
+    /// [`Right`]: crate::Either::Right
+    #[inline]
+    pub fn right_eq<T>(&self, other: &T) -> bool
+    where
+        R: PartialEq<T>,
+        T: ?Sized,
+    {
+        matches!(self, Right(x) if x == other)
+    }
+}
+
+/// The comparison between [`Either`] and [`Result`], where [`Left`]
+/// corresponds to [`Ok`] and [`Right`] corresponds to [`Err`].
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// let x: Either<String, i32> = Left("ok".to_owned());
+/// assert_eq!(x, Ok::<&str, i32>("ok"));
+/// assert_ne!(x, Err::<&str, i32>(0));
+/// assert_eq!(Err::<&str, i32>(0), Right::<String, i32>(0));
+/// ```
+impl<L, R, T, E> PartialEq<Result<T, E>> for Either<L, R>
+where
+    L: PartialEq<T>,
+    R: PartialEq<E>,
+{
+    #[inline]
+    fn eq(&self, other: &Result<T, E>) -> bool {
+        match (self, other) {
+            (Left(a), Ok(b)) => a == b,
+            (Right(a), Err(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<L, R, T, E> PartialEq<Either<L, R>> for Result<T, E>
+where
+    T: PartialEq<L>,
+    E: PartialEq<R>,
+{
+    #[inline]
+    fn eq(&self, other: &Either<L, R>) -> bool {
+        match (self, other) {
+            (Ok(a), Left(b)) => a == b,
+            (Err(a), Right(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The wrapper of an [`Either`], ordered by
+/// [`cmp_right_first`](Either::cmp_right_first): every [`Right`] value is
+/// less than every [`Left`] value.
+///
+/// The equality and the hash are the same as those of [`Either`].
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::cmp::RightFirst;
+/// use std::collections::BTreeMap;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(RightFirst(Left(1)), "one");
+/// map.insert(RightFirst(Right('a')), "a");
+/// assert_eq!(map.into_values().collect::<Vec<_>>(), ["a", "one"]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RightFirst<L, R>(pub Either<L, R>);
+
+impl<L, R> RightFirst<L, R> {
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+}
+
+impl<L, R> From<Either<L, R>> for RightFirst<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self(value)
+    }
+}
+
+impl<L, R> PartialOrd for RightFirst<L, R>
+where
+    L: Ord,
+    R: Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L, R> Ord for RightFirst<L, R>
+where
+    L: Ord,
+    R: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_right_first(&other.0)
+    }
+}
+
+/// The wrapper of an [`Either`], compared by
+/// [`cmp_by_value`](Either::cmp_by_value): the contained values only,
+/// promoting the [`Left`] value into `R` if the variants differ.
+///
+/// So `ByValue(Left(x))` and `ByValue(Right(y))` are equal if `x.promote()`
+/// equals `y`, and are the same key of a [`BTreeMap`].
+///
+/// The [`Ord`] of this wrapper is a total order only if [`Promote`] preserves
+/// the order and is injective, as documented on the trait; otherwise the keys
+/// of a [`BTreeMap`] may be lost or misplaced. Unlike [`RightFirst`],
+/// this wrapper does not implement [`Hash`], since the hash of [`Either`]
+/// includes the variant.
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// [`Left`]: crate::Either::Left
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::cmp::ByValue;
+/// use std::collections::BTreeMap;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(ByValue(Left::<u8, u32>(7)), "narrow");
+/// map.insert(ByValue(Right(7)), "wide");
+/// map.insert(ByValue(Right(300)), "large");
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map[&ByValue(Left(7))], "wide");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ByValue<L, R>(pub Either<L, R>);
+
+impl<L, R> ByValue<L, R> {
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+}
+
+impl<L, R> From<Either<L, R>> for ByValue<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self(value)
+    }
+}
+
+impl<L, R> PartialEq for ByValue<L, R>
+where
+    L: Ord + Promote<R> + Clone,
+    R: Ord,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<L, R> Eq for ByValue<L, R>
+where
+    L: Ord + Promote<R> + Clone,
+    R: Ord,
+{
+}
+
+impl<L, R> PartialOrd for ByValue<L, R>
+where
+    L: Ord + Promote<R> + Clone,
+    R: Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<L, R> Ord for ByValue<L, R>
+where
+    L: Ord + Promote<R> + Clone,
+    R: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_by_value(&other.0)
+    }
+}
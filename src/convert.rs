@@ -16,6 +16,32 @@
 //! - convert from `Either<Result<T, L>, Result<T, R>>` to `Result<T, Either<L, R>>`
 //! - convert from `Either<Result<L, E>, Result<R, E>>` to `Result<Either<L, R>, E>`
 //! - transpose between `Either<Result<T1, E1>, Result<T2, E2>>` and `Result<Either<T1, T2>, Either<E1, E2>>`
+//!
+//! # Interoperating with `Result`
+//!
+//! [`Either<L, R>`] and [`Result<L, R>`] convert into each other by [`From`],
+//! mapping [`Left`] to [`Ok`] and [`Right`] to [`Err`]. The methods
+//! [`as_result`](Either::as_result) and
+//! [`as_result_mut`](Either::as_result_mut) borrow an [`Either`] as a
+//! [`Result`] of references.
+//!
+//! For slices, the views are taken element-wise, e.g. by
+//! `slice.iter().map(Either::as_result)`. A `&[Either<L, R>]` is not
+//! reinterpreted as a `&[Result<L, R>]`: both types are `repr(Rust)`, so even
+//! with the same size and alignment, their discriminants and niches are not
+//! guaranteed to be laid out the same way.
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! let x: Either<i32, &str> = Ok(3).into();
+//! assert_eq!(x, Left(3));
+//! assert_eq!(Result::from(x), Ok(3));
+//! assert_eq!(Right::<i32, _>("three").as_result(), Err(&"three"));
+//!
+//! let slice = [Left(1), Right("two"), Left(3)];
+//! let results: Vec<_> = slice.iter().map(Either::as_result).collect();
+//! assert_eq!(results, [Ok(&1), Err(&"two"), Ok(&3)]);
+//! ```
 
 use crate::Either::{self, Left, Right};
 
@@ -26,10 +52,7 @@ where
 {
     #[inline]
     fn as_ref(&self) -> &T {
-        match self {
-            Left(x) => x.as_ref(),
-            Right(x) => x.as_ref(),
-        }
+        for_both!(self, x => x.as_ref())
     }
 }
 
@@ -40,10 +63,7 @@ where
 {
     #[inline]
     fn as_mut(&mut self) -> &mut T {
-        match self {
-            Left(x) => x.as_mut(),
-            Right(x) => x.as_mut(),
-        }
+        for_both!(self, x => x.as_mut())
     }
 }
 
@@ -127,3 +147,76 @@ impl<T1, T2, E1, E2> From<Result<Either<T1, T2>, Either<E1, E2>>>
         }
     }
 }
+
+impl<L, R> From<Result<L, R>> for Either<L, R> {
+    #[inline]
+    fn from(value: Result<L, R>) -> Self {
+        match value {
+            Ok(x) => Left(x),
+            Err(x) => Right(x),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for Result<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        match value {
+            Left(x) => Ok(x),
+            Right(x) => Err(x),
+        }
+    }
+}
+
+impl<L, R> Either<L, R> {
+    /// Converts from `&Either<L, R>` to `Result<&L, &R>`.
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output       |
+    /// | ---------- | ------------ |
+    /// | `Left(x)`  | `Ok(&x)`     |
+    /// | `Right(x)` | `Err(&x)`    |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let x: Either<i32, &str> = Left(3);
+    /// assert_eq!(x.as_result(), Ok(&3));
+    /// ```
+    #[inline]
+    pub const fn as_result(&self) -> Result<&L, &R> {
+        match self {
+            Left(x) => Ok(x),
+            Right(x) => Err(x),
+        }
+    }
+
+    /// Converts from `&mut Either<L, R>` to `Result<&mut L, &mut R>`.
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output          |
+    /// | ---------- | --------------- |
+    /// | `Left(x)`  | `Ok(&mut x)`    |
+    /// | `Right(x)` | `Err(&mut x)`   |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<i32, &str> = Left(3);
+    /// if let Ok(x) = x.as_result_mut() {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(x, Left(4));
+    /// ```
+    #[inline]
+    pub const fn as_result_mut(&mut self) -> Result<&mut L, &mut R> {
+        match self {
+            Left(x) => Ok(x),
+            Right(x) => Err(x),
+        }
+    }
+}
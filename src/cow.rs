@@ -0,0 +1,158 @@
+//! This module implements the conversions between [`Cow<'a, B>`] and
+//! `Either<&'a B, B::Owned>`, which represent the same borrowed-or-owned
+//! value: [`Cow::Borrowed`] corresponds to [`Left`] and [`Cow::Owned`]
+//! corresponds to [`Right`].
+//!
+//! The conversions are lossless in both directions, and
+//! [`into_owned`](Either::into_owned) and [`to_mut`](Either::to_mut) work
+//! the same as those of [`Cow`].
+//!
+//! [`Cow<'a, B>`]: Cow
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use std::borrow::Cow;
+//!
+//! fn normalize(s: &str) -> Either<&str, String> {
+//!     if s.contains('\t') {
+//!         Right(s.replace('\t', "    "))
+//!     } else {
+//!         Left(s)
+//!     }
+//! }
+//!
+//! let cow: Cow<'_, str> = normalize("a\tb").into();
+//! assert!(matches!(cow, Cow::Owned(_)));
+//! assert_eq!(cow, "a    b");
+//!
+//! let mut x = Either::from(Cow::Borrowed("either"));
+//! assert_eq!(x, Left("either"));
+//! x.to_mut().push('!');
+//! assert_eq!(x, Right("either!".to_owned()));
+//! assert_eq!(x.into_owned(), "either!");
+//! ```
+
+use std::borrow::{Cow, ToOwned};
+
+use crate::Either::{self, Left, Right};
+
+impl<'a, B> From<Cow<'a, B>> for Either<&'a B, B::Owned>
+where
+    B: ?Sized + ToOwned,
+{
+    #[inline]
+    fn from(value: Cow<'a, B>) -> Self {
+        match value {
+            Cow::Borrowed(x) => Left(x),
+            Cow::Owned(x) => Right(x),
+        }
+    }
+}
+
+impl<'a, B> From<Either<&'a B, B::Owned>> for Cow<'a, B>
+where
+    B: ?Sized + ToOwned,
+{
+    #[inline]
+    fn from(value: Either<&'a B, B::Owned>) -> Self {
+        match value {
+            Left(x) => Cow::Borrowed(x),
+            Right(x) => Cow::Owned(x),
+        }
+    }
+}
+
+impl<B> Either<&B, B::Owned>
+where
+    B: ?Sized + ToOwned,
+{
+    /// Returns the contained [`Right`] value, otherwise clones the borrowed
+    /// [`Left`] value into the owned type.
+    ///
+    /// This is the same as [`Cow::into_owned`].
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output         |
+    /// | ---------- | -------------- |
+    /// | `Left(x)`  | `x.to_owned()` |
+    /// | `Right(x)` | `x`            |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let x: Either<&[i32], Vec<i32>> = Left(&[1, 2]);
+    /// assert_eq!(x.into_owned(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn into_owned(self) -> B::Owned {
+        match self {
+            Left(x) => x.to_owned(),
+            Right(x) => x,
+        }
+    }
+
+    /// Clones the borrowed [`Left`] value into the [`Right`] variant if it is
+    /// [`Left`], then returns a mutable reference to the owned value.
+    ///
+    /// This is the same as [`Cow::to_mut`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<&str, String> = Left("either");
+    /// x.to_mut().make_ascii_uppercase();
+    /// assert_eq!(x, Right("EITHER".to_owned()));
+    /// ```
+    #[inline]
+    pub fn to_mut(&mut self) -> &mut B::Owned {
+        if let Left(x) = *self {
+            *self = Right(x.to_owned());
+        }
+        // SAFETY: `self` is `Right` here: it either was, or was just assigned.
+        unsafe { self.as_right_unchecked_mut() }
+    }
+}
+
+impl<L, R> Either<&L, &R>
+where
+    L: ?Sized + ToOwned,
+    R: ?Sized + ToOwned,
+{
+    /// Converts from `Either<&L, &R>` to `Either<L::Owned, R::Owned>` by
+    /// cloning the contained [`Left`] value or [`Right`] value into the
+    /// owned type.
+    ///
+    /// Unlike [`cloned`](Either::cloned), `L` and `R` may be unsized, e.g.
+    /// [`str`] or slices.
+    ///
+    /// NOTE: [`ToOwned`] itself cannot be implemented for [`Either`], since
+    /// it is implemented for all [`Clone`] types already.
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output                |
+    /// | ---------- | --------------------- |
+    /// | `Left(x)`  | `Left(x.to_owned())`  |
+    /// | `Right(x)` | `Right(x.to_owned())` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let x: Either<&str, &[u8]> = Left("either");
+    /// assert_eq!(x.owned(), Left("either".to_owned()));
+    /// ```
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub fn owned(self) -> Either<L::Owned, R::Owned> {
+        match self {
+            Left(x) => Left(x.to_owned()),
+            Right(x) => Right(x.to_owned()),
+        }
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! That means, `Either<L, R>` is deserializer, if and only if, both `L` and `R`
 //! are deserializers.
+//!
+//! The error type is [`EitherError`], whose side follows the active
+//! deserializer. See the [`serde_error`](crate::serde_error) module for
+//! details.
 
 use core::fmt::{self, Display, Formatter};
 
@@ -11,50 +15,103 @@ use serde::de::{
 };
 
 use crate::Either::{self, Left, Right};
+use crate::serde_error::EitherError;
 
-impl<L, R> Error for Either<L, R>
+impl<L, R> Error for EitherError<L, R>
 where
     L: Error,
     R: Error,
 {
     #[inline]
     fn custom<T: Display>(msg: T) -> Self {
-        Left(L::custom(msg))
+        EitherError::Both(L::custom(&msg), R::custom(&msg))
     }
 
     #[inline]
     fn invalid_type(unexp: Unexpected, exp: &dyn Expected) -> Self {
-        Left(L::invalid_type(unexp, exp))
+        EitherError::Both(L::invalid_type(unexp, exp), R::invalid_type(unexp, exp))
     }
 
     #[inline]
     fn invalid_value(unexp: Unexpected, exp: &dyn Expected) -> Self {
-        Left(L::invalid_value(unexp, exp))
+        EitherError::Both(L::invalid_value(unexp, exp), R::invalid_value(unexp, exp))
     }
 
     #[inline]
     fn invalid_length(len: usize, exp: &dyn Expected) -> Self {
-        Left(L::invalid_length(len, exp))
+        EitherError::Both(L::invalid_length(len, exp), R::invalid_length(len, exp))
     }
 
     #[inline]
     fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
-        Left(L::unknown_variant(variant, expected))
+        EitherError::Both(
+            L::unknown_variant(variant, expected),
+            R::unknown_variant(variant, expected),
+        )
     }
 
     #[inline]
     fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
-        Left(L::unknown_field(field, expected))
+        EitherError::Both(
+            L::unknown_field(field, expected),
+            R::unknown_field(field, expected),
+        )
     }
 
     #[inline]
     fn missing_field(field: &'static str) -> Self {
-        Left(L::missing_field(field))
+        EitherError::Both(L::missing_field(field), R::missing_field(field))
     }
 
     #[inline]
     fn duplicate_field(field: &'static str) -> Self {
-        Left(L::duplicate_field(field))
+        EitherError::Both(L::duplicate_field(field), R::duplicate_field(field))
+    }
+}
+
+impl<'de, L, R> Either<L, R>
+where
+    L: Deserializer<'de>,
+    R: Deserializer<'de>,
+{
+    /// Deserializes a value of type `T` with the contained deserializer,
+    /// returning the error on the side of the contained deserializer.
+    ///
+    /// Different from calling [`Deserialize::deserialize`] directly, the error
+    /// constructed by the [`Deserialize`] implementation is attributed to the
+    /// active side, instead of being returned as [`EitherError::Both`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of deserializing `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use serde::de::IntoDeserializer;
+    /// use serde::de::value::{Error as ValueError, StrDeserializer};
+    ///
+    /// type Json<'a> = serde_json::Deserializer<serde_json::de::StrRead<'a>>;
+    /// type Text<'a> = StrDeserializer<'a, ValueError>;
+    ///
+    /// let mut json = Json::from_str("[1, 2]");
+    /// let de: Either<&mut Json, Text> = Left(&mut json);
+    /// assert_eq!(de.deserialize_attributed::<Vec<u8>>().unwrap(), [1, 2]);
+    ///
+    /// let mut json = Json::from_str("1");
+    /// let de: Either<&mut Json, Text> = Left(&mut json);
+    /// assert!(matches!(de.deserialize_attributed::<(u8, u8)>(), Err(Left(_))));
+    ///
+    /// let de: Either<&mut Json, Text> = Right("text".into_deserializer());
+    /// assert!(matches!(de.deserialize_attributed::<u8>(), Err(Right(_))));
+    /// ```
+    pub fn deserialize_attributed<T>(self) -> Result<T, Either<L::Error, R::Error>>
+    where
+        T: Deserialize<'de>,
+    {
+        let is_left = self.is_left();
+        T::deserialize(self).map_err(|e| e.attribute(is_left))
     }
 }
 
@@ -63,15 +120,15 @@ where
     L: Deserializer<'de>,
     R: Deserializer<'de>,
 {
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_any(visitor).map_err(Left),
-            Right(x) => x.deserialize_any(visitor).map_err(Right),
+            Left(x) => x.deserialize_any(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_any(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -80,8 +137,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_bool(visitor).map_err(Left),
-            Right(x) => x.deserialize_bool(visitor).map_err(Right),
+            Left(x) => x.deserialize_bool(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_bool(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -90,8 +147,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_i8(visitor).map_err(Left),
-            Right(x) => x.deserialize_i8(visitor).map_err(Right),
+            Left(x) => x.deserialize_i8(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_i8(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -100,8 +157,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_i16(visitor).map_err(Left),
-            Right(x) => x.deserialize_i16(visitor).map_err(Right),
+            Left(x) => x.deserialize_i16(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_i16(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -110,8 +167,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_i32(visitor).map_err(Left),
-            Right(x) => x.deserialize_i32(visitor).map_err(Right),
+            Left(x) => x.deserialize_i32(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_i32(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -120,8 +177,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_i64(visitor).map_err(Left),
-            Right(x) => x.deserialize_i64(visitor).map_err(Right),
+            Left(x) => x.deserialize_i64(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_i64(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -130,8 +187,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_i128(visitor).map_err(Left),
-            Right(x) => x.deserialize_i128(visitor).map_err(Right),
+            Left(x) => x.deserialize_i128(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_i128(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -140,8 +197,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_u8(visitor).map_err(Left),
-            Right(x) => x.deserialize_u8(visitor).map_err(Right),
+            Left(x) => x.deserialize_u8(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_u8(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -150,8 +207,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_u16(visitor).map_err(Left),
-            Right(x) => x.deserialize_u16(visitor).map_err(Right),
+            Left(x) => x.deserialize_u16(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_u16(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -160,8 +217,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_u32(visitor).map_err(Left),
-            Right(x) => x.deserialize_u32(visitor).map_err(Right),
+            Left(x) => x.deserialize_u32(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_u32(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -170,8 +227,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_u64(visitor).map_err(Left),
-            Right(x) => x.deserialize_u64(visitor).map_err(Right),
+            Left(x) => x.deserialize_u64(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_u64(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -180,8 +237,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_u128(visitor).map_err(Left),
-            Right(x) => x.deserialize_u128(visitor).map_err(Right),
+            Left(x) => x.deserialize_u128(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_u128(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -190,8 +247,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_f32(visitor).map_err(Left),
-            Right(x) => x.deserialize_f32(visitor).map_err(Right),
+            Left(x) => x.deserialize_f32(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_f32(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -200,8 +257,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_f64(visitor).map_err(Left),
-            Right(x) => x.deserialize_f64(visitor).map_err(Right),
+            Left(x) => x.deserialize_f64(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_f64(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -210,8 +267,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_char(visitor).map_err(Left),
-            Right(x) => x.deserialize_char(visitor).map_err(Right),
+            Left(x) => x.deserialize_char(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_char(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -220,8 +277,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_str(visitor).map_err(Left),
-            Right(x) => x.deserialize_str(visitor).map_err(Right),
+            Left(x) => x.deserialize_str(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_str(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -230,8 +287,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_string(visitor).map_err(Left),
-            Right(x) => x.deserialize_string(visitor).map_err(Right),
+            Left(x) => x.deserialize_string(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_string(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -240,8 +297,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_bytes(visitor).map_err(Left),
-            Right(x) => x.deserialize_bytes(visitor).map_err(Right),
+            Left(x) => x.deserialize_bytes(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_bytes(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -250,8 +307,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_byte_buf(visitor).map_err(Left),
-            Right(x) => x.deserialize_byte_buf(visitor).map_err(Right),
+            Left(x) => x.deserialize_byte_buf(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_byte_buf(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -260,8 +317,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_option(visitor).map_err(Left),
-            Right(x) => x.deserialize_option(visitor).map_err(Right),
+            Left(x) => x.deserialize_option(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_option(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -270,8 +327,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_unit(visitor).map_err(Left),
-            Right(x) => x.deserialize_unit(visitor).map_err(Right),
+            Left(x) => x.deserialize_unit(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_unit(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -284,8 +341,12 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_unit_struct(name, visitor).map_err(Left),
-            Right(x) => x.deserialize_unit_struct(name, visitor).map_err(Right),
+            Left(x) => x
+                .deserialize_unit_struct(name, visitor)
+                .map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_unit_struct(name, visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -298,8 +359,12 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_newtype_struct(name, visitor).map_err(Left),
-            Right(x) => x.deserialize_newtype_struct(name, visitor).map_err(Right),
+            Left(x) => x
+                .deserialize_newtype_struct(name, visitor)
+                .map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_newtype_struct(name, visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -308,8 +373,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_seq(visitor).map_err(Left),
-            Right(x) => x.deserialize_seq(visitor).map_err(Right),
+            Left(x) => x.deserialize_seq(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_seq(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -318,8 +383,10 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_tuple(len, visitor).map_err(Left),
-            Right(x) => x.deserialize_tuple(len, visitor).map_err(Right),
+            Left(x) => x.deserialize_tuple(len, visitor).map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_tuple(len, visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -333,10 +400,12 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_tuple_struct(name, len, visitor).map_err(Left),
+            Left(x) => x
+                .deserialize_tuple_struct(name, len, visitor)
+                .map_err(EitherError::Left),
             Right(x) => x
                 .deserialize_tuple_struct(name, len, visitor)
-                .map_err(Right),
+                .map_err(EitherError::Right),
         }
     }
 
@@ -345,8 +414,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_map(visitor).map_err(Left),
-            Right(x) => x.deserialize_map(visitor).map_err(Right),
+            Left(x) => x.deserialize_map(visitor).map_err(EitherError::Left),
+            Right(x) => x.deserialize_map(visitor).map_err(EitherError::Right),
         }
     }
 
@@ -360,8 +429,12 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_struct(name, fields, visitor).map_err(Left),
-            Right(x) => x.deserialize_struct(name, fields, visitor).map_err(Right),
+            Left(x) => x
+                .deserialize_struct(name, fields, visitor)
+                .map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_struct(name, fields, visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -375,8 +448,12 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_enum(name, variants, visitor).map_err(Left),
-            Right(x) => x.deserialize_enum(name, variants, visitor).map_err(Right),
+            Left(x) => x
+                .deserialize_enum(name, variants, visitor)
+                .map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_enum(name, variants, visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -385,8 +462,10 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_identifier(visitor).map_err(Left),
-            Right(x) => x.deserialize_identifier(visitor).map_err(Right),
+            Left(x) => x.deserialize_identifier(visitor).map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_identifier(visitor)
+                .map_err(EitherError::Right),
         }
     }
 
@@ -395,16 +474,17 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.deserialize_ignored_any(visitor).map_err(Left),
-            Right(x) => x.deserialize_ignored_any(visitor).map_err(Right),
+            Left(x) => x
+                .deserialize_ignored_any(visitor)
+                .map_err(EitherError::Left),
+            Right(x) => x
+                .deserialize_ignored_any(visitor)
+                .map_err(EitherError::Right),
         }
     }
 
     fn is_human_readable(&self) -> bool {
-        match self {
-            Left(x) => x.is_human_readable(),
-            Right(x) => x.is_human_readable(),
-        }
+        for_both!(self, x => x.is_human_readable())
     }
 }
 
@@ -435,10 +515,7 @@ where
     type Value = Either<L::Value, R::Value>;
 
     fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => x.expecting(formatter),
-            Right(x) => x.expecting(formatter),
-        }
+        for_both!(self, x => x.expecting(formatter))
     }
 
     fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
@@ -717,15 +794,15 @@ where
     L: SeqAccess<'de>,
     R: SeqAccess<'de>,
 {
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
     where
         T: DeserializeSeed<'de>,
     {
         match self {
-            Left(x) => x.next_element_seed(seed).map_err(Left),
-            Right(x) => x.next_element_seed(seed).map_err(Right),
+            Left(x) => x.next_element_seed(seed).map_err(EitherError::Left),
+            Right(x) => x.next_element_seed(seed).map_err(EitherError::Right),
         }
     }
 
@@ -734,16 +811,13 @@ where
         T: Deserialize<'de>,
     {
         match self {
-            Left(x) => x.next_element().map_err(Left),
-            Right(x) => x.next_element().map_err(Right),
+            Left(x) => x.next_element().map_err(EitherError::Left),
+            Right(x) => x.next_element().map_err(EitherError::Right),
         }
     }
 
     fn size_hint(&self) -> Option<usize> {
-        match self {
-            Left(x) => x.size_hint(),
-            Right(x) => x.size_hint(),
-        }
+        for_both!(self, x => x.size_hint())
     }
 }
 
@@ -752,15 +826,15 @@ where
     L: MapAccess<'de>,
     R: MapAccess<'de>,
 {
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn next_key_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
     where
         T: DeserializeSeed<'de>,
     {
         match self {
-            Left(x) => x.next_key_seed(seed).map_err(Left),
-            Right(x) => x.next_key_seed(seed).map_err(Right),
+            Left(x) => x.next_key_seed(seed).map_err(EitherError::Left),
+            Right(x) => x.next_key_seed(seed).map_err(EitherError::Right),
         }
     }
 
@@ -769,8 +843,8 @@ where
         T: Deserialize<'de>,
     {
         match self {
-            Left(x) => x.next_key().map_err(Left),
-            Right(x) => x.next_key().map_err(Right),
+            Left(x) => x.next_key().map_err(EitherError::Left),
+            Right(x) => x.next_key().map_err(EitherError::Right),
         }
     }
 
@@ -779,8 +853,8 @@ where
         V: DeserializeSeed<'de>,
     {
         match self {
-            Left(x) => x.next_value_seed(seed).map_err(Left),
-            Right(x) => x.next_value_seed(seed).map_err(Right),
+            Left(x) => x.next_value_seed(seed).map_err(EitherError::Left),
+            Right(x) => x.next_value_seed(seed).map_err(EitherError::Right),
         }
     }
 
@@ -789,8 +863,8 @@ where
         T: Deserialize<'de>,
     {
         match self {
-            Left(x) => x.next_value().map_err(Left),
-            Right(x) => x.next_value().map_err(Right),
+            Left(x) => x.next_value().map_err(EitherError::Left),
+            Right(x) => x.next_value().map_err(EitherError::Right),
         }
     }
 
@@ -804,8 +878,8 @@ where
         V: DeserializeSeed<'de>,
     {
         match self {
-            Left(x) => x.next_entry_seed(kseed, vseed).map_err(Left),
-            Right(x) => x.next_entry_seed(kseed, vseed).map_err(Right),
+            Left(x) => x.next_entry_seed(kseed, vseed).map_err(EitherError::Left),
+            Right(x) => x.next_entry_seed(kseed, vseed).map_err(EitherError::Right),
         }
     }
 
@@ -815,16 +889,13 @@ where
         V: Deserialize<'de>,
     {
         match self {
-            Left(x) => x.next_entry().map_err(Left),
-            Right(x) => x.next_entry().map_err(Right),
+            Left(x) => x.next_entry().map_err(EitherError::Left),
+            Right(x) => x.next_entry().map_err(EitherError::Right),
         }
     }
 
     fn size_hint(&self) -> Option<usize> {
-        match self {
-            Left(x) => x.size_hint(),
-            Right(x) => x.size_hint(),
-        }
+        for_both!(self, x => x.size_hint())
     }
 }
 
@@ -833,7 +904,7 @@ where
     L: EnumAccess<'de>,
     R: EnumAccess<'de>,
 {
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
     type Variant = Either<L::Variant, R::Variant>;
 
     fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
@@ -843,11 +914,11 @@ where
         match self {
             Left(x) => match x.variant_seed(seed) {
                 Ok((value, variant)) => Ok((value, Left(variant))),
-                Err(err) => Err(Left(err)),
+                Err(err) => Err(EitherError::Left(err)),
             },
             Right(x) => match x.variant_seed(seed) {
                 Ok((value, variant)) => Ok((value, Right(variant))),
-                Err(err) => Err(Right(err)),
+                Err(err) => Err(EitherError::Right(err)),
             },
         }
     }
@@ -859,11 +930,11 @@ where
         match self {
             Left(x) => match x.variant() {
                 Ok((value, variant)) => Ok((value, Left(variant))),
-                Err(err) => Err(Left(err)),
+                Err(err) => Err(EitherError::Left(err)),
             },
             Right(x) => match x.variant() {
                 Ok((value, variant)) => Ok((value, Right(variant))),
-                Err(err) => Err(Right(err)),
+                Err(err) => Err(EitherError::Right(err)),
             },
         }
     }
@@ -874,12 +945,12 @@ where
     L: VariantAccess<'de>,
     R: VariantAccess<'de>,
 {
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn unit_variant(self) -> Result<(), Self::Error> {
         match self {
-            Left(x) => x.unit_variant().map_err(Left),
-            Right(x) => x.unit_variant().map_err(Right),
+            Left(x) => x.unit_variant().map_err(EitherError::Left),
+            Right(x) => x.unit_variant().map_err(EitherError::Right),
         }
     }
 
@@ -888,8 +959,8 @@ where
         T: DeserializeSeed<'de>,
     {
         match self {
-            Left(x) => x.newtype_variant_seed(seed).map_err(Left),
-            Right(x) => x.newtype_variant_seed(seed).map_err(Right),
+            Left(x) => x.newtype_variant_seed(seed).map_err(EitherError::Left),
+            Right(x) => x.newtype_variant_seed(seed).map_err(EitherError::Right),
         }
     }
 
@@ -898,8 +969,8 @@ where
         T: Deserialize<'de>,
     {
         match self {
-            Left(x) => x.newtype_variant().map_err(Left),
-            Right(x) => x.newtype_variant().map_err(Right),
+            Left(x) => x.newtype_variant().map_err(EitherError::Left),
+            Right(x) => x.newtype_variant().map_err(EitherError::Right),
         }
     }
 
@@ -908,8 +979,8 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.tuple_variant(len, visitor).map_err(Left),
-            Right(x) => x.tuple_variant(len, visitor).map_err(Right),
+            Left(x) => x.tuple_variant(len, visitor).map_err(EitherError::Left),
+            Right(x) => x.tuple_variant(len, visitor).map_err(EitherError::Right),
         }
     }
 
@@ -922,8 +993,10 @@ where
         V: Visitor<'de>,
     {
         match self {
-            Left(x) => x.struct_variant(fields, visitor).map_err(Left),
-            Right(x) => x.struct_variant(fields, visitor).map_err(Right),
+            Left(x) => x.struct_variant(fields, visitor).map_err(EitherError::Left),
+            Right(x) => x
+                .struct_variant(fields, visitor)
+                .map_err(EitherError::Right),
         }
     }
 }
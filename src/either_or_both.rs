@@ -0,0 +1,526 @@
+//! This module defines the type [`EitherOrBoth`], a companion of [`Either`]
+//! which may also contain both values.
+//!
+//! ```no_run
+//! # #[allow(dead_code)]
+//! enum EitherOrBoth<L, R> {
+//!    Left(L),
+//!    Right(R),
+//!    Both(L, R),
+//! }
+//! ```
+//!
+//! It is produced by the functions [`merge_join_by`], which merges two
+//! sorted iterators, and [`zip_longest`], which zips two iterators until
+//! both are exhausted.
+//!
+//! These are free functions rather than iterator methods, so they do not
+//! clash with the methods of the same names in `itertools`.
+//!
+//! # Example
+//!
+//! ```
+//! use either::EitherOrBoth::{self, Both, Left, Right};
+//! use either::either_or_both::{merge_join_by, zip_longest};
+//!
+//! let old = [("a", 1), ("b", 2), ("d", 4)];
+//! let new = [("b", 3), ("c", 3), ("d", 4)];
+//!
+//! let diff: Vec<_> = merge_join_by(&old, &new, |(x, _), (y, _)| x.cmp(y))
+//!     .filter_map(|x| match x {
+//!         Left((key, _)) => Some(format!("-{key}")),
+//!         Right((key, _)) => Some(format!("+{key}")),
+//!         Both(x, y) if x != y => Some(format!("~{}", x.0)),
+//!         Both(..) => None,
+//!     })
+//!     .collect();
+//! assert_eq!(diff, ["-a", "~b", "+c"]);
+//!
+//! let pairs: Vec<EitherOrBoth<i32, char>> = zip_longest([1, 2], ['x']).collect();
+//! assert_eq!(pairs, [Both(1, 'x'), Left(2)]);
+//! assert_eq!(pairs[1].or(0, '_'), (2, '_'));
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::{Fuse, FusedIterator, Peekable};
+
+use EitherOrBoth::{Both, Left, Right};
+
+use crate::Either;
+
+/// A value of type `L`, a value of type `R`, or both.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EitherOrBoth<L, R> {
+    /// Contains a value of type `L` only.
+    Left(L),
+    /// Contains a value of type `R` only.
+    Right(R),
+    /// Contains both a value of type `L` and a value of type `R`.
+    Both(L, R),
+}
+
+impl<T> EitherOrBoth<T, T> {
+    /// Returns the contained value, combining both values with function `f`
+    /// if the variant is [`Both`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Both, Left};
+    ///
+    /// assert_eq!(Left::<i32, i32>(1).reduce(i32::max), 1);
+    /// assert_eq!(Both(1, 2).reduce(i32::max), 2);
+    /// ```
+    #[inline]
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match self {
+            Left(x) | Right(x) => x,
+            Both(l, r) => f(l, r),
+        }
+    }
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Returns `true` if the variant is [`Left`].
+    #[inline]
+    #[must_use]
+    pub const fn is_left(&self) -> bool {
+        matches!(self, Left(_))
+    }
+
+    /// Returns `true` if the variant is [`Right`].
+    #[inline]
+    #[must_use]
+    pub const fn is_right(&self) -> bool {
+        matches!(self, Right(_))
+    }
+
+    /// Returns `true` if the variant is [`Both`].
+    #[inline]
+    #[must_use]
+    pub const fn is_both(&self) -> bool {
+        matches!(self, Both(..))
+    }
+
+    /// Returns `true` if the variant is [`Left`] or [`Both`].
+    #[inline]
+    #[must_use]
+    pub const fn has_left(&self) -> bool {
+        matches!(self, Left(_) | Both(..))
+    }
+
+    /// Returns `true` if the variant is [`Right`] or [`Both`].
+    #[inline]
+    #[must_use]
+    pub const fn has_right(&self) -> bool {
+        matches!(self, Right(_) | Both(..))
+    }
+
+    /// Converts from `&EitherOrBoth<L, R>` to `EitherOrBoth<&L, &R>`.
+    #[inline]
+    #[must_use]
+    pub const fn as_ref(&self) -> EitherOrBoth<&L, &R> {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(r),
+            Both(l, r) => Both(l, r),
+        }
+    }
+
+    /// Converts from `&mut EitherOrBoth<L, R>` to
+    /// `EitherOrBoth<&mut L, &mut R>`.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut(&mut self) -> EitherOrBoth<&mut L, &mut R> {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(r),
+            Both(l, r) => Both(l, r),
+        }
+    }
+
+    /// Returns the value of type `L`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Both, Right};
+    ///
+    /// assert_eq!(Both(1, 'x').left(), Some(1));
+    /// assert_eq!(Right::<i32, char>('x').left(), None);
+    /// ```
+    #[inline]
+    pub fn left(self) -> Option<L> {
+        match self {
+            Left(l) | Both(l, _) => Some(l),
+            Right(_) => None,
+        }
+    }
+
+    /// Returns the value of type `R`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Both, Left};
+    ///
+    /// assert_eq!(Both(1, 'x').right(), Some('x'));
+    /// assert_eq!(Left::<i32, char>(1).right(), None);
+    /// ```
+    #[inline]
+    pub fn right(self) -> Option<R> {
+        match self {
+            Right(r) | Both(_, r) => Some(r),
+            Left(_) => None,
+        }
+    }
+
+    /// Returns both values if the variant is [`Both`].
+    #[inline]
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            Both(l, r) => Some((l, r)),
+            Left(_) | Right(_) => None,
+        }
+    }
+
+    /// Returns the values of both types, if any.
+    #[inline]
+    pub fn left_and_right(self) -> (Option<L>, Option<R>) {
+        match self {
+            Left(l) => (Some(l), None),
+            Right(r) => (None, Some(r)),
+            Both(l, r) => (Some(l), Some(r)),
+        }
+    }
+
+    /// Converts `EitherOrBoth<L, R>` to `EitherOrBoth<R, L>`.
+    #[inline]
+    #[must_use]
+    pub fn flip(self) -> EitherOrBoth<R, L> {
+        match self {
+            Left(l) => Right(l),
+            Right(r) => Left(r),
+            Both(l, r) => Both(r, l),
+        }
+    }
+
+    /// Applies function `l` on the value of type `L` and function `r` on the
+    /// value of type `R`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Both, Left};
+    ///
+    /// let x: EitherOrBoth<i32, &str> = Both(2, "ab");
+    /// assert_eq!(x.map(|x| x * 2, str::len), Both(4, 2));
+    /// ```
+    #[inline]
+    pub fn map<T, U, F, G>(self, l: F, r: G) -> EitherOrBoth<T, U>
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> U,
+    {
+        match self {
+            Left(x) => Left(l(x)),
+            Right(y) => Right(r(y)),
+            Both(x, y) => Both(l(x), r(y)),
+        }
+    }
+
+    /// Applies function `f` on the value of type `L`, if any.
+    #[inline]
+    pub fn left_map<T, F>(self, f: F) -> EitherOrBoth<T, R>
+    where
+        F: FnOnce(L) -> T,
+    {
+        self.map(f, |r| r)
+    }
+
+    /// Applies function `f` on the value of type `R`, if any.
+    #[inline]
+    pub fn right_map<T, F>(self, f: F) -> EitherOrBoth<L, T>
+    where
+        F: FnOnce(R) -> T,
+    {
+        self.map(|l| l, f)
+    }
+
+    /// Returns both values, filling the missing one with `l` or `r`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Both, Right};
+    ///
+    /// assert_eq!(Right::<i32, char>('x').or(0, '_'), (0, 'x'));
+    /// assert_eq!(Both(1, 'x').or(0, '_'), (1, 'x'));
+    /// ```
+    #[inline]
+    pub fn or(self, l: L, r: R) -> (L, R) {
+        self.or_else(|| l, || r)
+    }
+
+    /// Returns both values, filling the missing one by calling `l` or `r`.
+    #[inline]
+    pub fn or_else<F, G>(self, l: F, r: G) -> (L, R)
+    where
+        F: FnOnce() -> L,
+        G: FnOnce() -> R,
+    {
+        match self {
+            Left(x) => (x, r()),
+            Right(y) => (l(), y),
+            Both(x, y) => (x, y),
+        }
+    }
+
+    /// Returns both values, filling the missing one with its default value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use either::EitherOrBoth::{self, Left};
+    ///
+    /// assert_eq!(Left::<i32, String>(1).or_default(), (1, String::new()));
+    /// ```
+    #[inline]
+    pub fn or_default(self) -> (L, R)
+    where
+        L: Default,
+        R: Default,
+    {
+        self.or_else(L::default, R::default)
+    }
+}
+
+impl<L, R> From<Either<L, R>> for EitherOrBoth<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        match value {
+            Either::Left(l) => Left(l),
+            Either::Right(r) => Right(r),
+        }
+    }
+}
+
+/// Converts [`Left`] and [`Right`] into [`Either`], returning both values as
+/// the error if the variant is [`Both`].
+///
+/// # Example
+///
+/// ```
+/// use either::{Either, EitherOrBoth};
+///
+/// let x: EitherOrBoth<i32, char> = EitherOrBoth::Right('x');
+/// assert_eq!(Either::try_from(x), Ok(Either::Right('x')));
+///
+/// let x: EitherOrBoth<i32, char> = EitherOrBoth::Both(1, 'x');
+/// assert_eq!(Either::try_from(x), Err((1, 'x')));
+/// ```
+impl<L, R> TryFrom<EitherOrBoth<L, R>> for Either<L, R> {
+    type Error = (L, R);
+
+    #[inline]
+    fn try_from(value: EitherOrBoth<L, R>) -> Result<Self, Self::Error> {
+        match value {
+            Left(l) => Ok(Either::Left(l)),
+            Right(r) => Ok(Either::Right(r)),
+            Both(l, r) => Err((l, r)),
+        }
+    }
+}
+
+/// Creates an iterator which merges the items of two iterators sorted by
+/// function `cmp`, yielding [`Left`] for the items of `left` only, [`Right`]
+/// for the items of `right` only, and [`Both`] for the items comparing equal.
+///
+/// # Example
+///
+/// ```
+/// use either::EitherOrBoth::{Both, Left, Right};
+/// use either::either_or_both::merge_join_by;
+///
+/// let merged = merge_join_by([1, 3, 4], [2, 3], i32::cmp);
+/// assert!(merged.eq([Left(1), Right(2), Both(3, 3), Left(4)]));
+/// ```
+#[inline]
+pub fn merge_join_by<I, J, F>(left: I, right: J, cmp: F) -> MergeJoinBy<I::IntoIter, J::IntoIter, F>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    F: FnMut(&I::Item, &J::Item) -> Ordering,
+{
+    MergeJoinBy {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+        cmp,
+    }
+}
+
+/// Creates an iterator which zips the items of two iterators until both are
+/// exhausted, yielding [`Both`] while both iterators have items, then the
+/// remaining items of the longer one.
+///
+/// # Example
+///
+/// ```
+/// use either::EitherOrBoth::{Both, Right};
+/// use either::either_or_both::zip_longest;
+///
+/// let zipped = zip_longest([1, 2], "abc".chars());
+/// assert!(zipped.eq([Both(1, 'a'), Both(2, 'b'), Right('c')]));
+/// ```
+#[inline]
+pub fn zip_longest<I, J>(left: I, right: J) -> ZipLongest<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+{
+    ZipLongest {
+        left: left.into_iter().fuse(),
+        right: right.into_iter().fuse(),
+    }
+}
+
+/// An iterator that merges two sorted iterators, yielding
+/// [`EitherOrBoth`] items.
+///
+/// This `struct` is created by [`merge_join_by`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeJoinBy<I: Iterator, J: Iterator, F> {
+    left: Peekable<I>,
+    right: Peekable<J>,
+    cmp: F,
+}
+
+impl<I, J, F> Clone for MergeJoinBy<I, J, F>
+where
+    I: Iterator<Item: Clone> + Clone,
+    J: Iterator<Item: Clone> + Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+}
+
+impl<I, J, F> Debug for MergeJoinBy<I, J, F>
+where
+    I: Iterator<Item: Debug> + Debug,
+    J: Iterator<Item: Debug> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeJoinBy")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, J, F> Iterator for MergeJoinBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator,
+    F: FnMut(&I::Item, &J::Item) -> Ordering,
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.left.peek(), self.right.peek()) {
+            (Some(l), Some(r)) => (self.cmp)(l, r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+        match ordering {
+            Ordering::Less => self.left.next().map(Left),
+            Ordering::Greater => self.right.next().map(Right),
+            Ordering::Equal => Some(Both(self.left.next()?, self.right.next()?)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l_lower, l_upper) = self.left.size_hint();
+        let (r_lower, r_upper) = self.right.size_hint();
+        let upper = match (l_upper, r_upper) {
+            (Some(l), Some(r)) => l.checked_add(r),
+            _ => None,
+        };
+        (l_lower.max(r_lower), upper)
+    }
+}
+
+impl<I, J, F> FusedIterator for MergeJoinBy<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    F: FnMut(&I::Item, &J::Item) -> Ordering,
+{
+}
+
+/// An iterator that zips two iterators until both are exhausted, yielding
+/// [`EitherOrBoth`] items.
+///
+/// This `struct` is created by [`zip_longest`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipLongest<I, J> {
+    left: Fuse<I>,
+    right: Fuse<J>,
+}
+
+impl<I, J> Iterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.next(), self.right.next()) {
+            (Some(l), Some(r)) => Some(Both(l, r)),
+            (Some(l), None) => Some(Left(l)),
+            (None, Some(r)) => Some(Right(r)),
+            (None, None) => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (l_lower, l_upper) = self.left.size_hint();
+        let (r_lower, r_upper) = self.right.size_hint();
+        let upper = match (l_upper, r_upper) {
+            (Some(l), Some(r)) => Some(l.max(r)),
+            _ => None,
+        };
+        (l_lower.max(r_lower), upper)
+    }
+}
+
+impl<I, J> ExactSizeIterator for ZipLongest<I, J>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+{
+}
+
+impl<I, J> FusedIterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+}
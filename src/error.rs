@@ -1,9 +1,93 @@
 //! This module implements the [`Error`] trait for [`Either`],
 //! if and only if, both `L` and `R` are [`Error`]s.
-
-use crate::Either::{self, Left, Right};
+//!
+//! The same trait is implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types are [`Error`]s.
+//!
+//! # Error semantics
+//!
+//! The implementation for [`Either`] is *transparent*: it behaves exactly as
+//! the contained error, forwarding [`Display`], [`source`], `description`,
+//! `cause` and, with the nightly `error_generic_member_access` feature,
+//! [`provide`] (e.g. for backtraces).
+//!
+//! The [`ErrorExt`] trait provides the other semantics:
+//!
+//! - [`wrapped`] returns a [`Wrapped`] error, whose [`source`] is the
+//!   contained error itself, and whose message names the variant.
+//! - [`left_context`] and [`right_context`] attach a message to one variant,
+//!   returning a [`Context`] error whose [`source`] is the contained error.
+//!
+//! [`source`]: Error::source
+//! [`provide`]: Error::provide
+//! [`wrapped`]: ErrorExt::wrapped
+//! [`left_context`]: ErrorExt::left_context
+//! [`right_context`]: ErrorExt::right_context
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use core::error::Error;
+//! use either::error::ErrorExt;
+//!
+//! let err: Either<std::io::Error, core::num::ParseIntError> = Right("x".parse::<u8>().unwrap_err());
+//!
+//! // Transparent.
+//! assert_eq!(err.to_string(), "invalid digit found in string");
+//! assert!(err.source().is_none());
+//!
+//! // Wrapping.
+//! let wrapped = err.wrapped();
+//! assert_eq!(wrapped.to_string(), "right error");
+//! assert_eq!(wrapped.source().unwrap().to_string(), "invalid digit found in string");
+//!
+//! // Context on the right variant only.
+//! let err = wrapped.into_inner().right_context("failed to parse the port");
+//! assert_eq!(err.to_string(), "failed to parse the port");
+//! assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+//! ```
+//!
+//! With the `error_generic_member_access` feature, the values provided by the
+//! contained error are available through all of the semantics.
+//!
+//! ```
+//! # #![cfg_attr(feature = "error_generic_member_access", feature(error_generic_member_access))]
+//! # #[cfg(feature = "error_generic_member_access")] {
+//! # use either::Either::{self, Left, Right};
+//! use core::error::{Error, Request, request_ref};
+//! use core::fmt::{self, Display, Formatter};
+//! use either::error::ErrorExt;
+//!
+//! #[derive(Debug)]
+//! struct Located(&'static str);
+//!
+//! impl Display for Located {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//!         f.write_str("located error")
+//!     }
+//! }
+//!
+//! impl Error for Located {
+//!     fn provide<'a>(&'a self, request: &mut Request<'a>) {
+//!         request.provide_ref::<str>(self.0);
+//!     }
+//! }
+//!
+//! let err: Either<Located, std::fmt::Error> = Left(Located("main.rs:1"));
+//! assert_eq!(request_ref::<str>(&err), Some("main.rs:1"));
+//! let err = err.left_context("context");
+//! assert_eq!(request_ref::<str>(&err), Some("main.rs:1"));
+//! # }
+//! ```
 
 use core::error::Error;
+#[cfg(feature = "error_generic_member_access")]
+use core::error::Request;
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::Either::{self, Left, Right};
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> Error for Either<L, R>
 where
@@ -11,9 +95,228 @@ where
     R: Error,
 {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Left(x) => x.source(),
-            Right(x) => x.source(),
+        for_both!(self, x => x.source())
+    }
+
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        for_both!(self, x => x.description())
+    }
+
+    #[allow(deprecated)]
+    fn cause(&self) -> Option<&dyn Error> {
+        for_both!(self, x => x.cause())
+    }
+
+    #[cfg(feature = "error_generic_member_access")]
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        for_both!(self, x => x.provide(request));
+    }
+}
+
+macro_rules! impl_error_nary {
+    ($name:ident { $($V:ident($T:ident)),+ }) => {
+        impl<$($T),+> Error for $name<$($T),+>
+        where
+            $($T: Error,)+
+        {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                match self {
+                    $($name::$V(x) => x.source(),)+
+                }
+            }
+
+            #[allow(deprecated)]
+            fn description(&self) -> &str {
+                match self {
+                    $($name::$V(x) => x.description(),)+
+                }
+            }
+
+            #[allow(deprecated)]
+            fn cause(&self) -> Option<&dyn Error> {
+                match self {
+                    $($name::$V(x) => x.cause(),)+
+                }
+            }
+
+            #[cfg(feature = "error_generic_member_access")]
+            fn provide<'a>(&'a self, request: &mut Request<'a>) {
+                match self {
+                    $($name::$V(x) => x.provide(request),)+
+                }
+            }
         }
+    };
+}
+
+for_each_nary!(impl_error_nary);
+
+/// Extension methods for [`Either`] of errors, selecting non-transparent
+/// error semantics.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait ErrorExt<L, R> {
+    /// Wraps the error, so that its [`source`](Error::source) is the
+    /// contained error, and its message names the variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::error::ErrorExt;
+    /// use core::error::Error;
+    ///
+    /// let err: Either<std::fmt::Error, std::io::Error> = Left(std::fmt::Error);
+    /// let wrapped = err.wrapped();
+    /// assert_eq!(wrapped.to_string(), "left error");
+    /// assert!(wrapped.source().unwrap().is::<std::fmt::Error>());
+    /// ```
+    fn wrapped(self) -> Wrapped<L, R>;
+
+    /// Attaches `context` to the [`Left`] error, leaving the [`Right`] error
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::error::ErrorExt;
+    /// use core::error::Error;
+    ///
+    /// let err: Either<std::fmt::Error, std::io::Error> = Left(std::fmt::Error);
+    /// let err = err.left_context("failed to format the report");
+    /// assert_eq!(err.to_string(), "failed to format the report");
+    /// assert!(err.source().unwrap().is::<std::fmt::Error>());
+    /// ```
+    fn left_context<C>(self, context: C) -> Either<Context<C, L>, R>;
+
+    /// Attaches `context` to the [`Right`] error, leaving the [`Left`] error
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::error::ErrorExt;
+    /// use core::error::Error;
+    ///
+    /// let err: Either<std::fmt::Error, std::fmt::Error> = Left(std::fmt::Error);
+    /// let err = err.right_context("unused");
+    /// assert_eq!(err.to_string(), "an error occurred when formatting an argument");
+    /// assert!(err.source().is_none());
+    /// ```
+    fn right_context<C>(self, context: C) -> Either<L, Context<C, R>>;
+}
+
+impl<L, R> ErrorExt<L, R> for Either<L, R>
+where
+    L: Error,
+    R: Error,
+{
+    #[inline]
+    fn wrapped(self) -> Wrapped<L, R> {
+        Wrapped(self)
+    }
+
+    #[inline]
+    fn left_context<C>(self, context: C) -> Either<Context<C, L>, R> {
+        self.left_map(|error| Context { context, error })
+    }
+
+    #[inline]
+    fn right_context<C>(self, context: C) -> Either<L, Context<C, R>> {
+        self.right_map(|error| Context { context, error })
+    }
+}
+
+/// The error wrapping an [`Either`] of errors, created by
+/// [`ErrorExt::wrapped`].
+///
+/// Its message is `left error` or `right error`, and its
+/// [`source`](Error::source) is the contained error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Wrapped<L, R>(pub Either<L, R>);
+
+impl<L, R> Wrapped<L, R> {
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+}
+
+impl<L, R> Display for Wrapped<L, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Left(_) => f.write_str("left error"),
+            Right(_) => f.write_str("right error"),
+        }
+    }
+}
+
+impl<L, R> Error for Wrapped<L, R>
+where
+    L: Error + 'static,
+    R: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(for_both!(&self.0, x => x as &(dyn Error + 'static)))
+    }
+
+    #[cfg(feature = "error_generic_member_access")]
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        self.0.provide(request);
+    }
+}
+
+/// The error with an attached message, created by
+/// [`ErrorExt::left_context`] and [`ErrorExt::right_context`].
+///
+/// Its message is the context, and its [`source`](Error::source) is the
+/// contained error.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Context<C, E> {
+    /// The attached message.
+    pub context: C,
+    /// The contained error.
+    pub error: E,
+}
+
+impl<C, E> Debug for Context<C, E>
+where
+    C: Display,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("context", &format_args!("{}", self.context))
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<C, E> Display for Context<C, E>
+where
+    C: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.context.fmt(f)
+    }
+}
+
+impl<C, E> Error for Context<C, E>
+where
+    C: Display,
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+
+    #[cfg(feature = "error_generic_member_access")]
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        self.error.provide(request);
     }
 }
@@ -8,12 +8,17 @@
 //! - [`LowerExp`] and [`UpperExp`]
 //!
 //! if and only if, both `L` and `R` implement the corresponding trait.
+//!
+//! The same traits are implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types implement the
+//! corresponding trait.
 
 use core::fmt::{
     self, Binary, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex, Write,
 };
 
-use crate::Either::{self, Left, Right};
+use crate::Either;
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> Write for Either<L, R>
 where
@@ -22,26 +27,17 @@ where
 {
     #[inline]
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        match self {
-            Left(x) => x.write_str(s),
-            Right(x) => x.write_str(s),
-        }
+        for_both!(self, x => x.write_str(s))
     }
 
     #[inline]
     fn write_char(&mut self, c: char) -> fmt::Result {
-        match self {
-            Left(x) => x.write_char(c),
-            Right(x) => x.write_char(c),
-        }
+        for_both!(self, x => x.write_char(c))
     }
 
     #[inline]
     fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
-        match self {
-            Left(x) => x.write_fmt(args),
-            Right(x) => x.write_fmt(args),
-        }
+        for_both!(self, x => x.write_fmt(args))
     }
 }
 
@@ -52,10 +48,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as Display>::fmt(x, f),
-            Right(x) => <R as Display>::fmt(x, f),
-        }
+        for_both!(self, x => Display::fmt(x, f))
     }
 }
 
@@ -66,10 +59,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as Binary>::fmt(x, f),
-            Right(x) => <R as Binary>::fmt(x, f),
-        }
+        for_both!(self, x => Binary::fmt(x, f))
     }
 }
 
@@ -80,10 +70,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as Octal>::fmt(x, f),
-            Right(x) => <R as Octal>::fmt(x, f),
-        }
+        for_both!(self, x => Octal::fmt(x, f))
     }
 }
 
@@ -94,10 +81,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as LowerHex>::fmt(x, f),
-            Right(x) => <R as LowerHex>::fmt(x, f),
-        }
+        for_both!(self, x => LowerHex::fmt(x, f))
     }
 }
 
@@ -108,10 +92,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as UpperHex>::fmt(x, f),
-            Right(x) => <R as UpperHex>::fmt(x, f),
-        }
+        for_both!(self, x => UpperHex::fmt(x, f))
     }
 }
 
@@ -122,10 +103,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as Pointer>::fmt(x, f),
-            Right(x) => <R as Pointer>::fmt(x, f),
-        }
+        for_both!(self, x => Pointer::fmt(x, f))
     }
 }
 
@@ -136,10 +114,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as LowerExp>::fmt(x, f),
-            Right(x) => <R as LowerExp>::fmt(x, f),
-        }
+        for_both!(self, x => LowerExp::fmt(x, f))
     }
 }
 
@@ -150,9 +125,60 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Left(x) => <L as UpperExp>::fmt(x, f),
-            Right(x) => <R as UpperExp>::fmt(x, f),
-        }
+        for_both!(self, x => UpperExp::fmt(x, f))
     }
 }
+
+macro_rules! impl_fmt_nary {
+    ($name:ident { $($V:ident($T:ident)),+ }) => {
+        impl<$($T),+> Write for $name<$($T),+>
+        where
+            $($T: Write,)+
+        {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self {
+                    $($name::$V(x) => x.write_str(s),)+
+                }
+            }
+
+            #[inline]
+            fn write_char(&mut self, c: char) -> fmt::Result {
+                match self {
+                    $($name::$V(x) => x.write_char(c),)+
+                }
+            }
+
+            #[inline]
+            fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+                match self {
+                    $($name::$V(x) => x.write_fmt(args),)+
+                }
+            }
+        }
+
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } Display);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } Binary);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } Octal);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } LowerHex);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } UpperHex);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } Pointer);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } LowerExp);
+        impl_fmt_nary!(@fmt $name { $($V($T)),+ } UpperExp);
+    };
+    (@fmt $name:ident { $($V:ident($T:ident)),+ } $Trait:ident) => {
+        impl<$($T),+> $Trait for $name<$($T),+>
+        where
+            $($T: $Trait,)+
+        {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $($name::$V(x) => $Trait::fmt(x, f),)+
+                }
+            }
+        }
+    };
+}
+
+for_each_nary!(impl_fmt_nary);
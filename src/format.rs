@@ -0,0 +1,440 @@
+//! This module implements the runtime selection between two serialization
+//! formats, on top of the [`Serializer`] and [`Deserializer`] implementations
+//! for [`Either`].
+//!
+//! - The [`Format`] trait describes a serialization format: how it is
+//!   identified (MIME types, file extensions and magic bytes), and how its
+//!   serializer and deserializer are constructed.
+//! - The [`FormatSelector`] picks one of two formats, returning the selected
+//!   one as `Either<&L, &R>`.
+//! - The [`to_writer`], [`from_reader`] and [`from_slice`] functions serialize
+//!   and deserialize values with the selected format, through the `Either`
+//!   serializer and deserializer. The errors are returned on the side of the
+//!   selected format, as `Either<L::Error, R::Error>`.
+//!
+//! Since [`Either`] implements [`Read`] and [`Write`], the reader and writer
+//! may be selected at runtime as well, e.g. `Either<Stdout, File>`.
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use std::io::Write;
+//! use std::path::Path;
+//!
+//! use either::format::{self, Format, FormatSelector};
+//! use postcard::ser_flavors::{Flavor, io::WriteFlavor};
+//! use serde_json::de::SliceRead;
+//!
+//! struct Json;
+//!
+//! impl Format for Json {
+//!     type Error = serde_json::Error;
+//!     type SerializerState<W: Write> = serde_json::Serializer<W>;
+//!     type Serializer<'a, W: Write + 'a> = &'a mut serde_json::Serializer<W>;
+//!     type DeserializerState<'de> = serde_json::Deserializer<SliceRead<'de>>;
+//!     type Deserializer<'a, 'de: 'a> = &'a mut serde_json::Deserializer<SliceRead<'de>>;
+//!
+//!     fn mime_types(&self) -> &[&str] {
+//!         &["application/json"]
+//!     }
+//!
+//!     fn extensions(&self) -> &[&str] {
+//!         &["json"]
+//!     }
+//!
+//!     fn matches_magic(&self, bytes: &[u8]) -> bool {
+//!         matches!(bytes.trim_ascii_start().first(), Some(b'{' | b'['))
+//!     }
+//!
+//!     fn serializer_state<W: Write>(&self, writer: W) -> Self::SerializerState<W> {
+//!         serde_json::Serializer::new(writer)
+//!     }
+//!
+//!     fn serializer<'a, W: Write + 'a>(
+//!         state: &'a mut Self::SerializerState<W>,
+//!     ) -> Self::Serializer<'a, W> {
+//!         state
+//!     }
+//!
+//!     fn finish<W: Write>(_: Self::SerializerState<W>) -> Result<(), Self::Error> {
+//!         Ok(())
+//!     }
+//!
+//!     fn deserializer_state<'de>(&self, input: &'de [u8]) -> Self::DeserializerState<'de> {
+//!         serde_json::Deserializer::from_slice(input)
+//!     }
+//!
+//!     fn deserializer<'a, 'de: 'a>(
+//!         state: &'a mut Self::DeserializerState<'de>,
+//!     ) -> Self::Deserializer<'a, 'de> {
+//!         state
+//!     }
+//!
+//!     fn end(mut state: Self::DeserializerState<'_>) -> Result<(), Self::Error> {
+//!         state.end()
+//!     }
+//! }
+//!
+//! struct Postcard;
+//!
+//! impl Format for Postcard {
+//!     type Error = postcard::Error;
+//!     type SerializerState<W: Write> = postcard::Serializer<WriteFlavor<W>>;
+//!     type Serializer<'a, W: Write + 'a> = &'a mut postcard::Serializer<WriteFlavor<W>>;
+//!     type DeserializerState<'de> = postcard::Deserializer<'de, postcard::de_flavors::Slice<'de>>;
+//!     type Deserializer<'a, 'de: 'a> = &'a mut Self::DeserializerState<'de>;
+//!
+//!     fn mime_types(&self) -> &[&str] {
+//!         &["application/x-postcard"]
+//!     }
+//!
+//!     fn extensions(&self) -> &[&str] {
+//!         &["postcard", "pc"]
+//!     }
+//!
+//!     fn serializer_state<W: Write>(&self, writer: W) -> Self::SerializerState<W> {
+//!         postcard::Serializer { output: WriteFlavor::new(writer) }
+//!     }
+//!
+//!     fn serializer<'a, W: Write + 'a>(
+//!         state: &'a mut Self::SerializerState<W>,
+//!     ) -> Self::Serializer<'a, W> {
+//!         state
+//!     }
+//!
+//!     fn finish<W: Write>(state: Self::SerializerState<W>) -> Result<(), Self::Error> {
+//!         state.output.finalize().map(drop)
+//!     }
+//!
+//!     fn deserializer_state<'de>(&self, input: &'de [u8]) -> Self::DeserializerState<'de> {
+//!         postcard::Deserializer::from_bytes(input)
+//!     }
+//!
+//!     fn deserializer<'a, 'de: 'a>(
+//!         state: &'a mut Self::DeserializerState<'de>,
+//!     ) -> Self::Deserializer<'a, 'de> {
+//!         state
+//!     }
+//!
+//!     fn end(state: Self::DeserializerState<'_>) -> Result<(), Self::Error> {
+//!         match state.finalize()? {
+//!             [] => Ok(()),
+//!             _ => Err(serde::de::Error::custom("trailing bytes")),
+//!         }
+//!     }
+//! }
+//!
+//! let selector = FormatSelector::new(Json, Postcard);
+//! assert!(selector.by_mime_type("application/json; charset=utf-8").unwrap().is_left());
+//! assert!(selector.by_extension("PC").unwrap().is_right());
+//! assert!(selector.by_path(Path::new("config.json")).unwrap().is_left());
+//! assert!(selector.by_path(Path::new("config.toml")).is_none());
+//!
+//! let value = (String::from("answer"), 42_u32);
+//!
+//! // Writes JSON to either standard output or a buffer.
+//! let format = selector.by_extension("json").unwrap();
+//! let mut writer: Either<std::io::Stdout, Vec<u8>> = Right(Vec::new());
+//! format::to_writer(format, &mut writer, &value).unwrap();
+//!
+//! let bytes = writer.right_unwrap();
+//! assert_eq!(bytes, br#"["answer",42]"#);
+//! let format = selector.by_magic(&bytes).unwrap();
+//! assert!(format.is_left());
+//! let decoded: (String, u32) = format::from_reader(format, bytes.as_slice()).unwrap();
+//! assert_eq!(decoded, value);
+//!
+//! let format = selector.by_mime_type("application/x-postcard").unwrap();
+//! let mut bytes = Vec::new();
+//! format::to_writer(format, &mut bytes, &value).unwrap();
+//! assert_eq!(bytes, b"\x06answer\x2a");
+//! assert_eq!(format::from_slice::<_, _, (String, u32)>(format, &bytes).unwrap(), value);
+//!
+//! // The error is on the side of the selected format.
+//! let error = format::from_slice::<_, _, (String, u32)>(format, b"\x06ans").unwrap_err();
+//! assert!(matches!(error, Right(postcard::Error::DeserializeUnexpectedEnd)));
+//!
+//! // The trailing data after the value is an error as well.
+//! assert!(format::from_slice::<_, _, (String, u32)>(format, b"\x06answer\x2a\x00").is_err());
+//! let json = selector.by_extension("json").unwrap();
+//! assert!(format::from_slice::<_, _, u32>(json, b"1 ").is_ok());
+//! let error = format::from_slice::<_, _, u32>(json, b"1 garbage").unwrap_err();
+//! assert!(error.left().is_some_and(|e| e.is_syntax()));
+//!
+//! // The I/O error of the reader keeps its kind in the message only.
+//! struct Failing;
+//! impl std::io::Read for Failing {
+//!     fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+//!         Err(std::io::ErrorKind::UnexpectedEof.into())
+//!     }
+//! }
+//! let error = format::from_reader::<_, _, _, u32>(json, Failing).unwrap_err();
+//! assert_eq!(error.left().unwrap().to_string(), "unexpected end of file (UnexpectedEof)");
+//! ```
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer};
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::Either::{self, Left, Right};
+
+/// A serialization format that can be selected by a [`FormatSelector`].
+///
+/// Since serializers and deserializers are usually implemented for mutable
+/// references, e.g. `&mut serde_json::Serializer<W>`, each format declares a
+/// state type owning the writer or the input, and a serializer or
+/// deserializer type borrowing the state.
+///
+/// See the [module-level documentation](self) for an example.
+pub trait Format {
+    /// The error type of both the serializer and the deserializer.
+    type Error: ser::Error + de::Error;
+
+    /// The state of the serializer, which owns the writer.
+    type SerializerState<W: Write>;
+
+    /// The serializer, which borrows the state.
+    type Serializer<'a, W: Write + 'a>: Serializer<Error = Self::Error>;
+
+    /// The state of the deserializer, which borrows the input.
+    type DeserializerState<'de>;
+
+    /// The deserializer, which borrows the state.
+    type Deserializer<'a, 'de: 'a>: Deserializer<'de, Error = Self::Error>;
+
+    /// Returns the MIME types of the format, e.g. `application/json`.
+    ///
+    /// The MIME types are matched ignoring ASCII case.
+    fn mime_types(&self) -> &[&str];
+
+    /// Returns the file extensions of the format without the leading dot,
+    /// e.g. `json`.
+    ///
+    /// The extensions are matched ignoring ASCII case.
+    fn extensions(&self) -> &[&str];
+
+    /// Returns `true` if `bytes`, the beginning of the input, is recognized as
+    /// the format.
+    ///
+    /// The default implementation never recognizes any input.
+    #[inline]
+    fn matches_magic(&self, bytes: &[u8]) -> bool {
+        let _ = bytes;
+        false
+    }
+
+    /// Creates the state of the serializer writing to `writer`.
+    fn serializer_state<W: Write>(&self, writer: W) -> Self::SerializerState<W>;
+
+    /// Borrows the serializer from the state.
+    fn serializer<'a, W: Write + 'a>(
+        state: &'a mut Self::SerializerState<W>,
+    ) -> Self::Serializer<'a, W>;
+
+    /// Finishes the serialization, e.g. flushing the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serialization can not be finished.
+    fn finish<W: Write>(state: Self::SerializerState<W>) -> Result<(), Self::Error>;
+
+    /// Creates the state of the deserializer reading from `input`.
+    fn deserializer_state<'de>(&self, input: &'de [u8]) -> Self::DeserializerState<'de>;
+
+    /// Borrows the deserializer from the state.
+    fn deserializer<'a, 'de: 'a>(
+        state: &'a mut Self::DeserializerState<'de>,
+    ) -> Self::Deserializer<'a, 'de>;
+
+    /// Finishes the deserialization, e.g. checking that there is no trailing
+    /// input after the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input has trailing data, or the
+    /// deserialization can not be finished.
+    fn end(state: Self::DeserializerState<'_>) -> Result<(), Self::Error>;
+}
+
+/// The selector between the formats `L` and `R`.
+///
+/// Each method returns the format on the [`Left`] side if it matches,
+/// otherwise the format on the [`Right`] side if it matches, otherwise
+/// [`None`].
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FormatSelector<L, R> {
+    /// The format on the left side.
+    pub left: L,
+    /// The format on the right side.
+    pub right: R,
+}
+
+impl<L, R> FormatSelector<L, R>
+where
+    L: Format,
+    R: Format,
+{
+    /// Creates a selector between the formats `left` and `right`.
+    #[inline]
+    #[must_use]
+    pub const fn new(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+
+    /// Selects the format by the MIME type, e.g. from a `Content-Type` header.
+    ///
+    /// The parameters after `;` (e.g. `charset=utf-8`) and the surrounding
+    /// whitespaces are ignored.
+    #[must_use]
+    pub fn by_mime_type(&self, mime_type: &str) -> Option<Either<&L, &R>> {
+        let essence = mime_type.split(';').next().unwrap_or_default().trim();
+        let matches = |names: &[&str]| names.iter().any(|x| x.eq_ignore_ascii_case(essence));
+        self.select(
+            matches(self.left.mime_types()),
+            matches(self.right.mime_types()),
+        )
+    }
+
+    /// Selects the format by the file extension, with or without the leading
+    /// dot.
+    #[must_use]
+    pub fn by_extension(&self, extension: &str) -> Option<Either<&L, &R>> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        let matches = |names: &[&str]| names.iter().any(|x| x.eq_ignore_ascii_case(extension));
+        self.select(
+            matches(self.left.extensions()),
+            matches(self.right.extensions()),
+        )
+    }
+
+    /// Selects the format by the extension of the file `path`.
+    #[must_use]
+    pub fn by_path(&self, path: &Path) -> Option<Either<&L, &R>> {
+        self.by_extension(path.extension()?.to_str()?)
+    }
+
+    /// Selects the format by the magic bytes at the beginning of the input.
+    #[must_use]
+    pub fn by_magic(&self, bytes: &[u8]) -> Option<Either<&L, &R>> {
+        self.select(
+            self.left.matches_magic(bytes),
+            self.right.matches_magic(bytes),
+        )
+    }
+
+    #[inline]
+    fn select(&self, left: bool, right: bool) -> Option<Either<&L, &R>> {
+        match (left, right) {
+            (true, _) => Some(Left(&self.left)),
+            (false, true) => Some(Right(&self.right)),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Serializes `value` to `writer` in the selected `format`.
+///
+/// # Errors
+///
+/// Returns the error of the selected format if `value` can not be serialized
+/// or the serialization can not be finished.
+pub fn to_writer<L, R, W, T>(
+    format: Either<&L, &R>,
+    writer: W,
+    value: &T,
+) -> Result<(), Either<L::Error, R::Error>>
+where
+    L: Format,
+    R: Format,
+    W: Write,
+    T: ?Sized + Serialize,
+{
+    let mut state = match format {
+        Left(f) => Left(f.serializer_state(writer)),
+        Right(f) => Right(f.serializer_state(writer)),
+    };
+    state
+        .as_mut()
+        .map(L::serializer, R::serializer)
+        .serialize_attributed(value)?;
+    match state {
+        Left(x) => L::finish(x).map_err(Left),
+        Right(x) => R::finish(x).map_err(Right),
+    }
+}
+
+/// Deserializes a value of type `T` from `reader` in the selected `format`.
+///
+/// The reader is read to the end before deserializing, and the whole input
+/// must be consumed by the value, as with [`from_slice`].
+///
+/// # Errors
+///
+/// Returns the error of the selected format if `reader` fails, the value
+/// can not be deserialized, or there is trailing data after the value.
+///
+/// The I/O error is converted by [`de::Error::custom`] into a message, which
+/// is lossy: the [`ErrorKind`] is kept as text only, e.g.
+/// `"unexpected end of file (UnexpectedEof)"`.
+///
+/// [`ErrorKind`]: std::io::ErrorKind
+pub fn from_reader<L, R, I, T>(
+    format: Either<&L, &R>,
+    mut reader: I,
+) -> Result<T, Either<L::Error, R::Error>>
+where
+    L: Format,
+    R: Format,
+    I: Read,
+    T: DeserializeOwned,
+{
+    let mut bytes = Vec::new();
+    match reader.read_to_end(&mut bytes) {
+        Ok(_) => from_slice(format, &bytes),
+        Err(e) => {
+            let message = format_args!("{e} ({:?})", e.kind());
+            Err(match format {
+                Left(_) => Left(de::Error::custom(message)),
+                Right(_) => Right(de::Error::custom(message)),
+            })
+        }
+    }
+}
+
+/// Deserializes a value of type `T` from `bytes` in the selected `format`.
+///
+/// The whole input must be consumed by the value, as checked by
+/// [`Format::end`].
+///
+/// # Errors
+///
+/// Returns the error of the selected format if the value can not be
+/// deserialized, or there is trailing data after the value.
+pub fn from_slice<'de, L, R, T>(
+    format: Either<&L, &R>,
+    bytes: &'de [u8],
+) -> Result<T, Either<L::Error, R::Error>>
+where
+    L: Format,
+    R: Format,
+    T: Deserialize<'de>,
+{
+    let mut state = match format {
+        Left(f) => Left(f.deserializer_state(bytes)),
+        Right(f) => Right(f.deserializer_state(bytes)),
+    };
+    let value = state
+        .as_mut()
+        .map(L::deserializer, R::deserializer)
+        .deserialize_attributed()?;
+    match state {
+        Left(x) => L::end(x).map_err(Left)?,
+        Right(x) => R::end(x).map_err(Right)?,
+    }
+    Ok(value)
+}
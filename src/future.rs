@@ -1,10 +1,21 @@
 //! This module implements the [`Future`] trait for [`Either`],
 //! if and only if, both `L` and `R` are [`Future`]s.
+//!
+//! The same trait is implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types are [`Future`]s
+//! with the same output.
+//!
+//! With the `futures` feature, the `FusedFuture` trait is also implemented
+//! for [`Either`], if and only if, both `L` and `R` are `FusedFuture`s.
 
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use crate::Either::{self, Left, Right};
+#[cfg(feature = "futures")]
+use futures_core::FusedFuture;
+
+use crate::Either;
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> Future for Either<L, R>
 where
@@ -15,9 +26,40 @@ where
 
     #[inline]
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match self.as_pin_mut() {
-            Left(x) => x.poll(cx),
-            Right(x) => x.poll(cx),
-        }
+        for_both!(self.as_pin_mut(), x => x.poll(cx))
     }
 }
+
+#[cfg(feature = "futures")]
+impl<L, R> FusedFuture for Either<L, R>
+where
+    L: FusedFuture,
+    R: FusedFuture<Output = L::Output>,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        for_both!(self, x => x.is_terminated())
+    }
+}
+
+macro_rules! impl_future_nary {
+    ($name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ }) => {
+        impl<$T0, $($T),+> Future for $name<$T0, $($T),+>
+        where
+            $T0: Future,
+            $($T: Future<Output = $T0::Output>,)+
+        {
+            type Output = $T0::Output;
+
+            #[inline]
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                match self.as_pin_mut() {
+                    $name::$V0(x) => x.poll(cx),
+                    $($name::$V(x) => x.poll(cx),)+
+                }
+            }
+        }
+    };
+}
+
+for_each_nary!(impl_future_nary);
@@ -1,11 +1,60 @@
 //! This module implements the [`Seek`], [`Read`], [`BufRead`] and [`Write`]
 //! traits for [`Either`], if and only if, both `L` and `R` implement the
 //! corresponding trait.
+//!
+//! On Unix, the [`AsFd`], [`AsRawFd`] and [`IntoRawFd`] traits, and the
+//! conversion into [`OwnedFd`], are also forwarded, so an [`Either`] of files,
+//! pipes or sockets can be passed to `poll`-like interfaces.
+//! [`Either::is_terminal`] forwards [`IsTerminal`], which is sealed and cannot
+//! be implemented outside of the standard library.
+//!
+//! The same traits are implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types implement the
+//! corresponding trait.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(unix)] {
+//! # use either::Either::{self, Left, Right};
+//! use std::fs::File;
+//! use std::io::{PipeReader, PipeWriter, Read, Seek, Write};
+//! use std::os::fd::{AsFd, AsRawFd, OwnedFd};
+//!
+//! let (reader, mut writer) = std::io::pipe().unwrap();
+//! let mut source: Either<PipeReader, File> = Left(reader);
+//! assert_eq!(source.as_fd().as_raw_fd(), source.as_raw_fd());
+//!
+//! writer.write_all(b"hello").unwrap();
+//! drop(writer);
+//! let mut buf = String::new();
+//! source.read_to_string(&mut buf).unwrap();
+//! assert_eq!(buf, "hello");
+//!
+//! let sink: Either<PipeWriter, File> = Right(tempfile::tempfile().unwrap());
+//! let fd = sink.as_raw_fd();
+//! let owned = OwnedFd::from(sink);
+//! assert_eq!(owned.as_raw_fd(), fd);
+//!
+//! let mut file = File::from(owned);
+//! file.write_all(b"world").unwrap();
+//! file.rewind().unwrap();
+//! let mut buf = String::new();
+//! file.read_to_string(&mut buf).unwrap();
+//! assert_eq!(buf, "world");
+//!
+//! let x: Either<std::io::Stdin, File> = Right(file);
+//! assert!(!x.is_terminal());
+//! # }
+//! ```
 
 use std::fmt::Arguments;
-use std::io::{self, BufRead, Read, Seek, Write};
+use std::io::{self, BufRead, IsTerminal, Read, Seek, Write};
+#[cfg(unix)]
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, OwnedFd, RawFd};
 
-use crate::Either::{self, Left, Right};
+use crate::Either;
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> Seek for Either<L, R>
 where
@@ -14,37 +63,27 @@ where
 {
     #[inline]
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
-        match self {
-            Left(x) => x.seek(pos),
-            Right(x) => x.seek(pos),
-        }
+        for_both!(self, x => x.seek(pos))
     }
 
     #[inline]
     fn rewind(&mut self) -> io::Result<()> {
-        match self {
-            Left(x) => x.rewind(),
-            Right(x) => x.rewind(),
-        }
+        for_both!(self, x => x.rewind())
     }
 
     #[inline]
     fn stream_position(&mut self) -> io::Result<u64> {
-        match self {
-            Left(x) => x.stream_position(),
-            Right(x) => x.stream_position(),
-        }
+        for_both!(self, x => x.stream_position())
     }
 
     #[inline]
     fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
-        match self {
-            Left(x) => x.seek_relative(offset),
-            Right(x) => x.seek_relative(offset),
-        }
+        for_both!(self, x => x.seek_relative(offset))
     }
 }
 
+// `Read::read_buf`, `Read::read_buf_exact` and `Write::write_all_vectored`
+// are unstable, and are left to their default implementations until then.
 impl<L, R> Read for Either<L, R>
 where
     L: Read,
@@ -52,42 +91,27 @@ where
 {
     #[inline]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read(buf),
-            Right(x) => x.read(buf),
-        }
+        for_both!(self, x => x.read(buf))
     }
 
     #[inline]
     fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read_vectored(bufs),
-            Right(x) => x.read_vectored(bufs),
-        }
+        for_both!(self, x => x.read_vectored(bufs))
     }
 
     #[inline]
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read_to_end(buf),
-            Right(x) => x.read_to_end(buf),
-        }
+        for_both!(self, x => x.read_to_end(buf))
     }
 
     #[inline]
     fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read_to_string(buf),
-            Right(x) => x.read_to_string(buf),
-        }
+        for_both!(self, x => x.read_to_string(buf))
     }
 
     #[inline]
     fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        match self {
-            Left(x) => x.read_exact(buf),
-            Right(x) => x.read_exact(buf),
-        }
+        for_both!(self, x => x.read_exact(buf))
     }
 }
 
@@ -98,42 +122,27 @@ where
 {
     #[inline]
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
-        match self {
-            Left(x) => x.fill_buf(),
-            Right(x) => x.fill_buf(),
-        }
+        for_both!(self, x => x.fill_buf())
     }
 
     #[inline]
     fn consume(&mut self, amt: usize) {
-        match self {
-            Left(x) => x.consume(amt),
-            Right(x) => x.consume(amt),
-        }
+        for_both!(self, x => x.consume(amt))
     }
 
     #[inline]
     fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read_until(byte, buf),
-            Right(x) => x.read_until(byte, buf),
-        }
+        for_both!(self, x => x.read_until(byte, buf))
     }
 
     #[inline]
     fn skip_until(&mut self, byte: u8) -> io::Result<usize> {
-        match self {
-            Left(x) => x.skip_until(byte),
-            Right(x) => x.skip_until(byte),
-        }
+        for_both!(self, x => x.skip_until(byte))
     }
 
     #[inline]
     fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
-        match self {
-            Left(x) => x.read_line(buf),
-            Right(x) => x.read_line(buf),
-        }
+        for_both!(self, x => x.read_line(buf))
     }
 }
 
@@ -144,41 +153,313 @@ where
 {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        match self {
-            Left(x) => x.write(buf),
-            Right(x) => x.write(buf),
-        }
+        for_both!(self, x => x.write(buf))
     }
 
     #[inline]
     fn flush(&mut self) -> io::Result<()> {
-        match self {
-            Left(x) => x.flush(),
-            Right(x) => x.flush(),
-        }
+        for_both!(self, x => x.flush())
     }
 
     #[inline]
     fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
-        match self {
-            Left(x) => x.write_vectored(bufs),
-            Right(x) => x.write_vectored(bufs),
-        }
+        for_both!(self, x => x.write_vectored(bufs))
     }
 
     #[inline]
     fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        match self {
-            Left(x) => x.write_all(buf),
-            Right(x) => x.write_all(buf),
-        }
+        for_both!(self, x => x.write_all(buf))
     }
 
     #[inline]
     fn write_fmt(&mut self, fmt: Arguments<'_>) -> io::Result<()> {
-        match self {
-            Left(x) => x.write_fmt(fmt),
-            Right(x) => x.write_fmt(fmt),
-        }
+        for_both!(self, x => x.write_fmt(fmt))
     }
 }
+
+#[cfg(unix)]
+impl<L, R> AsFd for Either<L, R>
+where
+    L: AsFd,
+    R: AsFd,
+{
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        for_both!(self, x => x.as_fd())
+    }
+}
+
+#[cfg(unix)]
+impl<L, R> AsRawFd for Either<L, R>
+where
+    L: AsRawFd,
+    R: AsRawFd,
+{
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        for_both!(self, x => x.as_raw_fd())
+    }
+}
+
+#[cfg(unix)]
+impl<L, R> IntoRawFd for Either<L, R>
+where
+    L: IntoRawFd,
+    R: IntoRawFd,
+{
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        for_both!(self, x => x.into_raw_fd())
+    }
+}
+
+#[cfg(unix)]
+impl<L, R> From<Either<L, R>> for OwnedFd
+where
+    L: Into<OwnedFd>,
+    R: Into<OwnedFd>,
+{
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        for_both!(value, x => x.into())
+    }
+}
+
+impl<L, R> Either<L, R>
+where
+    L: IsTerminal,
+    R: IsTerminal,
+{
+    /// Returns `true` if the contained value refers to a terminal, as
+    /// [`IsTerminal::is_terminal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::io::{IsTerminal, Stdout};
+    ///
+    /// let x: Either<Stdout, std::fs::File> = Left(std::io::stdout());
+    /// assert_eq!(x.is_terminal(), std::io::stdout().is_terminal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        for_both!(self, x => x.is_terminal())
+    }
+}
+
+macro_rules! impl_io_nary {
+    ($name:ident { $($V:ident($T:ident)),+ }) => {
+        impl<$($T),+> Seek for $name<$($T),+>
+        where
+            $($T: Seek,)+
+        {
+            #[inline]
+            fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+                match self {
+                    $($name::$V(x) => x.seek(pos),)+
+                }
+            }
+
+            #[inline]
+            fn rewind(&mut self) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.rewind(),)+
+                }
+            }
+
+            #[inline]
+            fn stream_position(&mut self) -> io::Result<u64> {
+                match self {
+                    $($name::$V(x) => x.stream_position(),)+
+                }
+            }
+
+            #[inline]
+            fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.seek_relative(offset),)+
+                }
+            }
+        }
+
+        impl<$($T),+> Read for $name<$($T),+>
+        where
+            $($T: Read,)+
+        {
+            #[inline]
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read(buf),)+
+                }
+            }
+
+            #[inline]
+            fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read_vectored(bufs),)+
+                }
+            }
+
+            #[inline]
+            fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read_to_end(buf),)+
+                }
+            }
+
+            #[inline]
+            fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read_to_string(buf),)+
+                }
+            }
+
+            #[inline]
+            fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.read_exact(buf),)+
+                }
+            }
+        }
+
+        impl<$($T),+> BufRead for $name<$($T),+>
+        where
+            $($T: BufRead,)+
+        {
+            #[inline]
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                match self {
+                    $($name::$V(x) => x.fill_buf(),)+
+                }
+            }
+
+            #[inline]
+            fn consume(&mut self, amt: usize) {
+                match self {
+                    $($name::$V(x) => x.consume(amt),)+
+                }
+            }
+
+            #[inline]
+            fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read_until(byte, buf),)+
+                }
+            }
+
+            #[inline]
+            fn skip_until(&mut self, byte: u8) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.skip_until(byte),)+
+                }
+            }
+
+            #[inline]
+            fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.read_line(buf),)+
+                }
+            }
+        }
+
+        impl<$($T),+> Write for $name<$($T),+>
+        where
+            $($T: Write,)+
+        {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.write(buf),)+
+                }
+            }
+
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.flush(),)+
+                }
+            }
+
+            #[inline]
+            fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+                match self {
+                    $($name::$V(x) => x.write_vectored(bufs),)+
+                }
+            }
+
+            #[inline]
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.write_all(buf),)+
+                }
+            }
+
+            #[inline]
+            fn write_fmt(&mut self, fmt: Arguments<'_>) -> io::Result<()> {
+                match self {
+                    $($name::$V(x) => x.write_fmt(fmt),)+
+                }
+            }
+        }
+    };
+}
+
+for_each_nary!(impl_io_nary);
+
+#[cfg(unix)]
+macro_rules! impl_fd_nary {
+    ($name:ident { $($V:ident($T:ident)),+ }) => {
+        impl<$($T),+> AsFd for $name<$($T),+>
+        where
+            $($T: AsFd,)+
+        {
+            #[inline]
+            fn as_fd(&self) -> BorrowedFd<'_> {
+                match self {
+                    $($name::$V(x) => x.as_fd(),)+
+                }
+            }
+        }
+
+        impl<$($T),+> AsRawFd for $name<$($T),+>
+        where
+            $($T: AsRawFd,)+
+        {
+            #[inline]
+            fn as_raw_fd(&self) -> RawFd {
+                match self {
+                    $($name::$V(x) => x.as_raw_fd(),)+
+                }
+            }
+        }
+
+        impl<$($T),+> IntoRawFd for $name<$($T),+>
+        where
+            $($T: IntoRawFd,)+
+        {
+            #[inline]
+            fn into_raw_fd(self) -> RawFd {
+                match self {
+                    $($name::$V(x) => x.into_raw_fd(),)+
+                }
+            }
+        }
+
+        impl<$($T),+> From<$name<$($T),+>> for OwnedFd
+        where
+            $($T: Into<OwnedFd>,)+
+        {
+            #[inline]
+            fn from(value: $name<$($T),+>) -> Self {
+                match value {
+                    $($name::$V(x) => x.into(),)+
+                }
+            }
+        }
+    };
+}
+
+#[cfg(unix)]
+for_each_nary!(impl_fd_nary);
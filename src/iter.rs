@@ -3,10 +3,81 @@
 //!
 //! Also, the [`IntoIterator`] trait is implemented for `&Either` and
 //! `&mut Either`.
+//!
+//! The same traits are implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types are [`Iterator`]s
+//! of the same item type, producing an [`IntoIterN`].
+//!
+//! # Performance
+//!
+//! [`IntoIter`] and [`IntoIterN`] forward `next` and the methods of
+//! [`Iterator`] and [`DoubleEndedIterator`] which consume or search the
+//! iterator (e.g. `fold`, `all`, `find`, `max`, `reduce` and `rfold`) to the
+//! contained iterator, so the internal iteration of the contained iterator
+//! (e.g. [`Chain::fold`]) is preserved. `rposition` is not forwarded, since
+//! its bounds on `Self` cannot be carried over to the contained iterator.
+//!
+//! The `try_fold`, `try_rfold`, `advance_by` and `advance_back_by` methods can
+//! only be overridden on nightly, and back `try_for_each`, `sum::<Option<_>>`
+//! and `skip` among others. Enabling the `try_fold` feature forwards
+//! `try_fold` and `try_rfold`, and enabling the `advance_by` feature forwards
+//! `advance_by` and `advance_back_by`. The `trusted_len` feature implements
+//! [`TrustedLen`] if all of the contained iterators implement it.
+//!
+//! [`Chain::fold`]: core::iter::Chain
+//! [`TrustedLen`]: https://doc.rust-lang.org/core/iter/trait.TrustedLen.html
+//!
+//! # Iterating over `Either` items
+//!
+//! The [`EitherIterExt`] trait extends every [`Iterator`] with adapters for
+//! splitting, filtering, mapping and flattening [`Either`] items:
+//!
+//! - [`partition_either`] splits the [`Left`] and [`Right`] items into two
+//!   collections
+//! - [`partition_map`] maps each item into an [`Either`] and splits the
+//!   results into two collections
+//! - [`lefts`] and [`rights`] yield only the [`Left`] or [`Right`] values
+//! - [`map_either`] applies one of two functions on each item
+//! - [`flatten_either`] flattens the items whose both variants are iterable
+//!
+//! The free functions [`merge_join_by`] and [`zip_longest`] of the
+//! [`either_or_both`](crate::either_or_both) module combine two iterators
+//! into [`EitherOrBoth`] items instead.
+//!
+//! [`EitherOrBoth`]: crate::EitherOrBoth
+//! [`partition_either`]: EitherIterExt::partition_either
+//! [`partition_map`]: EitherIterExt::partition_map
+//! [`lefts`]: EitherIterExt::lefts
+//! [`rights`]: EitherIterExt::rights
+//! [`map_either`]: EitherIterExt::map_either
+//! [`flatten_either`]: EitherIterExt::flatten_either
+//! [`merge_join_by`]: crate::either_or_both::merge_join_by
+//! [`zip_longest`]: crate::either_or_both::zip_longest
+//!
+//! # Collecting into `Either`
+//!
+//! As with [`Result`], an iterator of [`Either`] items can be collected,
+//! summed or multiplied into an [`Either`], which short-circuits at the first
+//! [`Right`] value. The wrapper [`CollectRights`] collects the [`Right`]
+//! values and short-circuits at the first [`Left`] value instead.
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! let x: Either<Vec<i32>, &str> = [Left(1), Right("error"), Left(2)].into_iter().collect();
+//! assert_eq!(x, Right("error"));
+//! ```
 
-use core::iter::FusedIterator;
+use core::cmp::Ordering;
+#[cfg(feature = "trusted_len")]
+use core::iter::TrustedLen;
+use core::iter::{Flatten, FusedIterator, Product, Sum};
+#[cfg(feature = "advance_by")]
+use core::num::NonZero;
+#[cfg(feature = "try_fold")]
+use core::ops::Try;
 
 use crate::Either::{self, Left, Right};
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> IntoIterator for Either<L, R>
 where
@@ -64,10 +135,91 @@ where
     R: Extend<A>,
 {
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
-        match self {
-            Left(x) => x.extend(iter),
-            Right(x) => x.extend(iter),
-        }
+        for_both!(self, x => x.extend(iter))
+    }
+}
+
+/// Collects the [`Left`] values of an iterator into `C`, short-circuiting at
+/// the first [`Right`] value, which is returned instead.
+///
+/// This is the same as collecting [`Result`]s into a [`Result`]. The items
+/// after the first [`Right`] value are not consumed. See [`CollectRights`]
+/// for the mirrored form.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// let items: [Either<i32, &str>; 2] = [Left(1), Left(2)];
+/// let x: Either<Vec<i32>, &str> = items.into_iter().collect();
+/// assert_eq!(x, Left(vec![1, 2]));
+///
+/// // Stops at the first `Right` value, even for an infinite iterator.
+/// let mut consumed = 0;
+/// let x: Either<Vec<u32>, u32> = (0..)
+///     .inspect(|_| consumed += 1)
+///     .map(|x| if x < 3 { Left(x) } else { Right(x) })
+///     .collect();
+/// assert_eq!(x, Right(3));
+/// assert_eq!(consumed, 4);
+/// ```
+impl<L, R, C> FromIterator<Either<L, R>> for Either<C, R>
+where
+    C: FromIterator<L>,
+{
+    fn from_iter<T: IntoIterator<Item = Either<L, R>>>(iter: T) -> Self {
+        collect_lefts(iter.into_iter(), |x| C::from_iter(x))
+    }
+}
+
+/// Sums the [`Left`] values of an iterator, short-circuiting at the first
+/// [`Right`] value, which is returned instead.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// let mut consumed = 0;
+/// let x: Either<i32, &str> = [Left(1), Right("overflow"), Left(2)]
+///     .into_iter()
+///     .inspect(|_| consumed += 1)
+///     .sum();
+/// assert_eq!(x, Right("overflow"));
+/// assert_eq!(consumed, 2);
+///
+/// let x: Either<i32, &str> = [Left(1), Left(2)].into_iter().sum();
+/// assert_eq!(x, Left(3));
+/// ```
+impl<L, R, T> Sum<Either<L, R>> for Either<T, R>
+where
+    T: Sum<L>,
+{
+    fn sum<I: Iterator<Item = Either<L, R>>>(iter: I) -> Self {
+        collect_lefts(iter, |x| x.sum())
+    }
+}
+
+/// Multiplies the [`Left`] values of an iterator, short-circuiting at the
+/// first [`Right`] value, which is returned instead.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// let x: Either<i32, &str> = [Left(2), Left(3)].into_iter().product();
+/// assert_eq!(x, Left(6));
+///
+/// let x: Either<i32, &str> = (1..)
+///     .map(|x| if x < 5 { Left(x) } else { Right("stop") })
+///     .product();
+/// assert_eq!(x, Right("stop"));
+/// ```
+impl<L, R, T> Product<Either<L, R>> for Either<T, R>
+where
+    T: Product<L>,
+{
+    fn product<I: Iterator<Item = Either<L, R>>>(iter: I) -> Self {
+        collect_lefts(iter, |x| x.product())
     }
 }
 
@@ -98,42 +250,27 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        match self.0 {
-            Left(ref mut x) => x.next(),
-            Right(ref mut x) => x.next(),
-        }
+        for_both!(self.0, ref mut x => x.next())
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        match self.0 {
-            Left(ref x) => x.size_hint(),
-            Right(ref x) => x.size_hint(),
-        }
+        for_both!(self.0, ref x => x.size_hint())
     }
 
     #[inline]
     fn count(self) -> usize {
-        match self.0 {
-            Left(x) => x.count(),
-            Right(x) => x.count(),
-        }
+        for_both!(self.0, x => x.count())
     }
 
     #[inline]
     fn last(self) -> Option<Self::Item> {
-        match self.0 {
-            Left(x) => x.last(),
-            Right(x) => x.last(),
-        }
+        for_both!(self.0, x => x.last())
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        match self.0 {
-            Left(ref mut x) => x.nth(n),
-            Right(ref mut x) => x.nth(n),
-        }
+        for_both!(self.0, ref mut x => x.nth(n))
     }
 
     #[inline]
@@ -141,10 +278,7 @@ where
     where
         F: FnMut(B, Self::Item) -> B,
     {
-        match self.0 {
-            Left(x) => x.fold(init, f),
-            Right(x) => x.fold(init, f),
-        }
+        for_both!(self.0, x => x.fold(init, f))
     }
 
     #[inline]
@@ -152,18 +286,12 @@ where
     where
         F: FnMut(Self::Item),
     {
-        match self.0 {
-            Left(x) => x.for_each(f),
-            Right(x) => x.for_each(f),
-        }
+        for_both!(self.0, x => x.for_each(f))
     }
 
     #[inline]
     fn collect<B: FromIterator<Self::Item>>(self) -> B {
-        match self.0 {
-            Left(x) => x.collect(),
-            Right(x) => x.collect(),
-        }
+        for_both!(self.0, x => x.collect())
     }
 
     #[inline]
@@ -172,10 +300,7 @@ where
         B: Default + Extend<Self::Item>,
         F: FnMut(&Self::Item) -> bool,
     {
-        match self.0 {
-            Left(x) => x.partition(f),
-            Right(x) => x.partition(f),
-        }
+        for_both!(self.0, x => x.partition(f))
     }
 
     #[inline]
@@ -183,10 +308,7 @@ where
     where
         F: FnMut(Self::Item) -> bool,
     {
-        match self.0 {
-            Left(ref mut x) => x.all(f),
-            Right(ref mut x) => x.all(f),
-        }
+        for_both!(self.0, ref mut x => x.all(f))
     }
 
     #[inline]
@@ -194,10 +316,7 @@ where
     where
         F: FnMut(Self::Item) -> bool,
     {
-        match self.0 {
-            Left(ref mut x) => x.any(f),
-            Right(ref mut x) => x.any(f),
-        }
+        for_both!(self.0, ref mut x => x.any(f))
     }
 
     #[inline]
@@ -205,10 +324,7 @@ where
     where
         P: FnMut(&Self::Item) -> bool,
     {
-        match self.0 {
-            Left(ref mut x) => x.find(predicate),
-            Right(ref mut x) => x.find(predicate),
-        }
+        for_both!(self.0, ref mut x => x.find(predicate))
     }
 
     #[inline]
@@ -216,10 +332,7 @@ where
     where
         F: FnMut(Self::Item) -> Option<B>,
     {
-        match self.0 {
-            Left(ref mut x) => x.find_map(f),
-            Right(ref mut x) => x.find_map(f),
-        }
+        for_both!(self.0, ref mut x => x.find_map(f))
     }
 
     #[inline]
@@ -227,10 +340,7 @@ where
     where
         P: FnMut(Self::Item) -> bool,
     {
-        match self.0 {
-            Left(ref mut x) => x.position(predicate),
-            Right(ref mut x) => x.position(predicate),
-        }
+        for_both!(self.0, ref mut x => x.position(predicate))
     }
 
     #[inline]
@@ -238,10 +348,7 @@ where
     where
         Self::Item: Ord,
     {
-        match self.0 {
-            Left(x) => x.max(),
-            Right(x) => x.max(),
-        }
+        for_both!(self.0, x => x.max())
     }
 
     #[inline]
@@ -249,10 +356,7 @@ where
     where
         Self::Item: Ord,
     {
-        match self.0 {
-            Left(x) => x.min(),
-            Right(x) => x.min(),
-        }
+        for_both!(self.0, x => x.min())
     }
 
     #[inline]
@@ -260,10 +364,7 @@ where
     where
         F: FnMut(&Self::Item) -> B,
     {
-        match self.0 {
-            Left(x) => x.max_by_key(f),
-            Right(x) => x.max_by_key(f),
-        }
+        for_both!(self.0, x => x.max_by_key(f))
     }
 
     #[inline]
@@ -271,10 +372,7 @@ where
     where
         F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
     {
-        match self.0 {
-            Left(x) => x.max_by(compare),
-            Right(x) => x.max_by(compare),
-        }
+        for_both!(self.0, x => x.max_by(compare))
     }
 
     #[inline]
@@ -282,10 +380,7 @@ where
     where
         F: FnMut(&Self::Item) -> B,
     {
-        match self.0 {
-            Left(x) => x.min_by_key(f),
-            Right(x) => x.min_by_key(f),
-        }
+        for_both!(self.0, x => x.min_by_key(f))
     }
 
     #[inline]
@@ -293,10 +388,7 @@ where
     where
         F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
     {
-        match self.0 {
-            Left(x) => x.min_by(compare),
-            Right(x) => x.min_by(compare),
-        }
+        for_both!(self.0, x => x.min_by(compare))
     }
 
     #[inline]
@@ -304,10 +396,7 @@ where
     where
         S: core::iter::Sum<Self::Item>,
     {
-        match self.0 {
-            Left(x) => x.sum(),
-            Right(x) => x.sum(),
-        }
+        for_both!(self.0, x => x.sum())
     }
 
     #[inline]
@@ -315,10 +404,7 @@ where
     where
         P: core::iter::Product<Self::Item>,
     {
-        match self.0 {
-            Left(x) => x.product(),
-            Right(x) => x.product(),
-        }
+        for_both!(self.0, x => x.product())
     }
 
     #[inline]
@@ -327,10 +413,7 @@ where
         I: IntoIterator<Item = Self::Item>,
         Self::Item: Ord,
     {
-        match self.0 {
-            Left(x) => x.cmp(other),
-            Right(x) => x.cmp(other),
-        }
+        for_both!(self.0, x => x.cmp(other))
     }
 
     #[inline]
@@ -339,10 +422,7 @@ where
         I: IntoIterator,
         Self::Item: PartialOrd<I::Item>,
     {
-        match self.0 {
-            Left(x) => x.partial_cmp(other),
-            Right(x) => x.partial_cmp(other),
-        }
+        for_both!(self.0, x => x.partial_cmp(other))
     }
 
     #[inline]
@@ -351,10 +431,7 @@ where
         I: IntoIterator,
         Self::Item: PartialEq<I::Item>,
     {
-        match self.0 {
-            Left(x) => x.eq(other),
-            Right(x) => x.eq(other),
-        }
+        for_both!(self.0, x => x.eq(other))
     }
 
     #[inline]
@@ -362,10 +439,7 @@ where
     where
         F: FnMut(&Self::Item, &Self::Item) -> bool,
     {
-        match self.0 {
-            Left(x) => x.is_sorted_by(compare),
-            Right(x) => x.is_sorted_by(compare),
-        }
+        for_both!(self.0, x => x.is_sorted_by(compare))
     }
 
     #[inline]
@@ -374,10 +448,84 @@ where
         F: FnMut(Self::Item) -> K,
         K: PartialOrd,
     {
-        match self.0 {
-            Left(x) => x.is_sorted_by_key(f),
-            Right(x) => x.is_sorted_by_key(f),
-        }
+        for_both!(self.0, x => x.is_sorted_by_key(f))
+    }
+
+    #[inline]
+    fn is_sorted(self) -> bool
+    where
+        Self::Item: PartialOrd,
+    {
+        for_both!(self.0, x => x.is_sorted())
+    }
+
+    #[inline]
+    fn reduce<F>(self, f: F) -> Option<Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        for_both!(self.0, x => x.reduce(f))
+    }
+
+    #[inline]
+    fn ne<I>(self, other: I) -> bool
+    where
+        I: IntoIterator,
+        Self::Item: PartialEq<I::Item>,
+    {
+        for_both!(self.0, x => x.ne(other))
+    }
+
+    #[inline]
+    fn lt<I>(self, other: I) -> bool
+    where
+        I: IntoIterator,
+        Self::Item: PartialOrd<I::Item>,
+    {
+        for_both!(self.0, x => x.lt(other))
+    }
+
+    #[inline]
+    fn le<I>(self, other: I) -> bool
+    where
+        I: IntoIterator,
+        Self::Item: PartialOrd<I::Item>,
+    {
+        for_both!(self.0, x => x.le(other))
+    }
+
+    #[inline]
+    fn gt<I>(self, other: I) -> bool
+    where
+        I: IntoIterator,
+        Self::Item: PartialOrd<I::Item>,
+    {
+        for_both!(self.0, x => x.gt(other))
+    }
+
+    #[inline]
+    fn ge<I>(self, other: I) -> bool
+    where
+        I: IntoIterator,
+        Self::Item: PartialOrd<I::Item>,
+    {
+        for_both!(self.0, x => x.ge(other))
+    }
+
+    #[cfg(feature = "try_fold")]
+    #[inline]
+    fn try_fold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        F: FnMut(B, Self::Item) -> T,
+        T: Try<Output = B>,
+    {
+        for_both!(self.0, ref mut x => x.try_fold(init, f))
+    }
+
+    #[cfg(feature = "advance_by")]
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+        for_both!(self.0, ref mut x => x.advance_by(n))
     }
 }
 
@@ -388,18 +536,12 @@ where
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        match self.0 {
-            Left(ref mut x) => x.next_back(),
-            Right(ref mut x) => x.next_back(),
-        }
+        for_both!(self.0, ref mut x => x.next_back())
     }
 
     #[inline]
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        match self.0 {
-            Left(ref mut x) => x.nth_back(n),
-            Right(ref mut x) => x.nth_back(n),
-        }
+        for_both!(self.0, ref mut x => x.nth_back(n))
     }
 
     #[inline]
@@ -407,10 +549,7 @@ where
     where
         F: FnMut(B, Self::Item) -> B,
     {
-        match self.0 {
-            Left(x) => x.rfold(init, f),
-            Right(x) => x.rfold(init, f),
-        }
+        for_both!(self.0, x => x.rfold(init, f))
     }
 
     #[inline]
@@ -418,10 +557,23 @@ where
     where
         P: FnMut(&Self::Item) -> bool,
     {
-        match self.0 {
-            Left(ref mut x) => x.rfind(predicate),
-            Right(ref mut x) => x.rfind(predicate),
-        }
+        for_both!(self.0, ref mut x => x.rfind(predicate))
+    }
+
+    #[cfg(feature = "try_fold")]
+    #[inline]
+    fn try_rfold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        F: FnMut(B, Self::Item) -> T,
+        T: Try<Output = B>,
+    {
+        for_both!(self.0, ref mut x => x.try_rfold(init, f))
+    }
+
+    #[cfg(feature = "advance_by")]
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+        for_both!(self.0, ref mut x => x.advance_back_by(n))
     }
 }
 
@@ -432,10 +584,7 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        match self.0 {
-            Left(ref x) => x.len(),
-            Right(ref x) => x.len(),
-        }
+        for_both!(self.0, ref x => x.len())
     }
 }
 
@@ -445,3 +594,1006 @@ where
     R: FusedIterator<Item = L::Item>,
 {
 }
+
+// SAFETY: `IntoIter` forwards `size_hint` to the contained iterator, which
+// reports an accurate length since both `L` and `R` implement `TrustedLen`.
+#[cfg(feature = "trusted_len")]
+unsafe impl<L, R> TrustedLen for IntoIter<L, R>
+where
+    L: TrustedLen,
+    R: TrustedLen<Item = L::Item>,
+{
+}
+
+/// An extension trait for [`Iterator`]s, providing adapters for working with
+/// [`Either`] items.
+///
+/// This trait is implemented for every [`Iterator`].
+///
+/// See the [module-level documentation](self) for more details.
+pub trait EitherIterExt: Iterator {
+    /// Consumes the iterator, collecting the [`Left`] values into `A` and the
+    /// [`Right`] values into `B`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let items: [Either<i32, &str>; 4] = [Left(1), Right("a"), Left(2), Right("b")];
+    /// let (l, r): (Vec<i32>, Vec<&str>) = items.into_iter().partition_either();
+    /// assert_eq!(l, [1, 2]);
+    /// assert_eq!(r, ["a", "b"]);
+    /// ```
+    #[inline]
+    fn partition_either<L, R, A, B>(self) -> (A, B)
+    where
+        Self: Sized + Iterator<Item = Either<L, R>>,
+        A: Default + Extend<L>,
+        B: Default + Extend<R>,
+    {
+        self.partition_map(core::convert::identity)
+    }
+
+    /// Consumes the iterator, mapping each item into an [`Either`] with
+    /// function `f`, then collecting the [`Left`] values into `A` and the
+    /// [`Right`] values into `B`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let (even, odd): (Vec<i32>, Vec<i32>) = (1..=5)
+    ///     .partition_map(|x| if x % 2 == 0 { Left(x) } else { Right(x) });
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// ```
+    fn partition_map<L, R, A, B, F>(self, mut f: F) -> (A, B)
+    where
+        Self: Sized,
+        A: Default + Extend<L>,
+        B: Default + Extend<R>,
+        F: FnMut(Self::Item) -> Either<L, R>,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+        self.for_each(|x| match f(x) {
+            Left(x) => left.extend(Some(x)),
+            Right(x) => right.extend(Some(x)),
+        });
+        (left, right)
+    }
+
+    /// Creates an iterator which yields the [`Left`] values only,
+    /// discarding the [`Right`] values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let items: [Either<i32, &str>; 3] = [Left(1), Right("a"), Left(2)];
+    /// assert!(items.into_iter().lefts().eq([1, 2]));
+    /// ```
+    #[inline]
+    fn lefts<L, R>(self) -> Lefts<Self>
+    where
+        Self: Sized + Iterator<Item = Either<L, R>>,
+    {
+        Lefts(self)
+    }
+
+    /// Creates an iterator which yields the [`Right`] values only,
+    /// discarding the [`Left`] values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let items: [Either<i32, &str>; 3] = [Left(1), Right("a"), Left(2)];
+    /// assert!(items.into_iter().rights().eq(["a"]));
+    /// ```
+    #[inline]
+    fn rights<L, R>(self) -> Rights<Self>
+    where
+        Self: Sized + Iterator<Item = Either<L, R>>,
+    {
+        Rights(self)
+    }
+
+    /// Creates an iterator which applies one of two functions on each item,
+    /// returning the result re-wrapped in [`Left`] or [`Right`].
+    ///
+    /// See also [`Either::map`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let items: [Either<i32, &str>; 2] = [Left(1), Right("a")];
+    /// let mapped = items.into_iter().map_either(|x| x * 2, str::len);
+    /// assert!(mapped.eq([Left(2), Right(1)]));
+    /// ```
+    #[inline]
+    fn map_either<L, R, T, U, F, G>(self, l: F, r: G) -> MapEither<Self, F, G>
+    where
+        Self: Sized + Iterator<Item = Either<L, R>>,
+        F: FnMut(L) -> T,
+        G: FnMut(R) -> U,
+    {
+        MapEither { iter: self, l, r }
+    }
+
+    /// Creates an iterator which flattens the items, if and only if, both
+    /// variants of the items are [`IntoIterator`]s of the same item type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// # use either::iter::EitherIterExt;
+    /// let items: [Either<Vec<i32>, [i32; 1]>; 2] = [Left(vec![1, 2]), Right([3])];
+    /// assert!(items.into_iter().flatten_either().eq([1, 2, 3]));
+    /// ```
+    #[inline]
+    fn flatten_either<L, R>(self) -> Flatten<Self>
+    where
+        Self: Sized + Iterator<Item = Either<L, R>>,
+        L: IntoIterator,
+        R: IntoIterator<Item = L::Item>,
+    {
+        self.flatten()
+    }
+}
+
+impl<I: Iterator> EitherIterExt for I {}
+
+/// An iterator that yields the [`Left`] values of the underlying iterator.
+///
+/// This `struct` is created by [`EitherIterExt::lefts`].
+#[derive(Clone, Copy, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Lefts<I>(I);
+
+impl<I> Lefts<I> {
+    /// Returns the underlying iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I, L, R> Iterator for Lefts<I>
+where
+    I: Iterator<Item = Either<L, R>>,
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find_map(Either::left)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.0.filter(Either::is_left).count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.fold(init, |acc, x| match x {
+            Left(x) => f(acc, x),
+            Right(_) => acc,
+        })
+    }
+}
+
+impl<I, L, R> DoubleEndedIterator for Lefts<I>
+where
+    I: DoubleEndedIterator<Item = Either<L, R>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().rev().find_map(Either::left)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.rfold(init, |acc, x| match x {
+            Left(x) => f(acc, x),
+            Right(_) => acc,
+        })
+    }
+}
+
+impl<I, L, R> FusedIterator for Lefts<I> where I: FusedIterator<Item = Either<L, R>> {}
+
+/// An iterator that yields the [`Right`] values of the underlying iterator.
+///
+/// This `struct` is created by [`EitherIterExt::rights`].
+#[derive(Clone, Copy, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Rights<I>(I);
+
+impl<I> Rights<I> {
+    /// Returns the underlying iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I, L, R> Iterator for Rights<I>
+where
+    I: Iterator<Item = Either<L, R>>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find_map(Either::right)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.0.filter(Either::is_right).count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.fold(init, |acc, x| match x {
+            Left(_) => acc,
+            Right(x) => f(acc, x),
+        })
+    }
+}
+
+impl<I, L, R> DoubleEndedIterator for Rights<I>
+where
+    I: DoubleEndedIterator<Item = Either<L, R>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.by_ref().rev().find_map(Either::right)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.rfold(init, |acc, x| match x {
+            Left(_) => acc,
+            Right(x) => f(acc, x),
+        })
+    }
+}
+
+impl<I, L, R> FusedIterator for Rights<I> where I: FusedIterator<Item = Either<L, R>> {}
+
+/// An iterator that applies one of two functions on each [`Either`] item of
+/// the underlying iterator.
+///
+/// This `struct` is created by [`EitherIterExt::map_either`].
+#[derive(Clone, Copy, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapEither<I, F, G> {
+    iter: I,
+    l: F,
+    r: G,
+}
+
+impl<I, F, G> MapEither<I, F, G> {
+    /// Returns the underlying iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, L, R, T, U, F, G> Iterator for MapEither<I, F, G>
+where
+    I: Iterator<Item = Either<L, R>>,
+    F: FnMut(L) -> T,
+    G: FnMut(R) -> U,
+{
+    type Item = Either<T, U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next()?;
+        Some(x.map(&mut self.l, &mut self.r))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let x = self.iter.nth(n)?;
+        Some(x.map(&mut self.l, &mut self.r))
+    }
+
+    #[inline]
+    fn fold<B, H>(self, init: B, mut f: H) -> B
+    where
+        H: FnMut(B, Self::Item) -> B,
+    {
+        let Self { iter, mut l, mut r } = self;
+        iter.fold(init, |acc, x| f(acc, x.map(&mut l, &mut r)))
+    }
+}
+
+impl<I, L, R, T, U, F, G> DoubleEndedIterator for MapEither<I, F, G>
+where
+    I: DoubleEndedIterator<Item = Either<L, R>>,
+    F: FnMut(L) -> T,
+    G: FnMut(R) -> U,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next_back()?;
+        Some(x.map(&mut self.l, &mut self.r))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let x = self.iter.nth_back(n)?;
+        Some(x.map(&mut self.l, &mut self.r))
+    }
+
+    #[inline]
+    fn rfold<B, H>(self, init: B, mut f: H) -> B
+    where
+        H: FnMut(B, Self::Item) -> B,
+    {
+        let Self { iter, mut l, mut r } = self;
+        iter.rfold(init, |acc, x| f(acc, x.map(&mut l, &mut r)))
+    }
+}
+
+impl<I, L, R, T, U, F, G> ExactSizeIterator for MapEither<I, F, G>
+where
+    I: ExactSizeIterator<Item = Either<L, R>>,
+    F: FnMut(L) -> T,
+    G: FnMut(R) -> U,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, L, R, T, U, F, G> FusedIterator for MapEither<I, F, G>
+where
+    I: FusedIterator<Item = Either<L, R>>,
+    F: FnMut(L) -> T,
+    G: FnMut(R) -> U,
+{
+}
+
+/// The wrapper of an [`Either`] collecting the [`Right`] values of an
+/// iterator, short-circuiting at the first [`Left`] value.
+///
+/// This is the mirrored form of the [`FromIterator`], [`Sum`] and [`Product`]
+/// implementations of [`Either`], which collect the [`Left`] values.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::iter::CollectRights;
+///
+/// let items: [Either<&str, i32>; 3] = [Right(1), Right(2), Right(3)];
+/// let x: CollectRights<&str, Vec<i32>> = items.into_iter().collect();
+/// assert_eq!(x.into_inner(), Right(vec![1, 2, 3]));
+///
+/// let mut consumed = 0;
+/// let x: CollectRights<&str, i32> = [Right(1), Left("stop"), Right(2)]
+///     .into_iter()
+///     .inspect(|_| consumed += 1)
+///     .sum();
+/// assert_eq!(x.into_inner(), Left("stop"));
+/// assert_eq!(consumed, 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollectRights<L, C>(pub Either<L, C>);
+
+impl<L, C> CollectRights<L, C> {
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, C> {
+        self.0
+    }
+}
+
+impl<L, R, C> FromIterator<Either<L, R>> for CollectRights<L, C>
+where
+    C: FromIterator<R>,
+{
+    fn from_iter<T: IntoIterator<Item = Either<L, R>>>(iter: T) -> Self {
+        Self(collect_lefts(iter.into_iter().map(Either::flip), |x| C::from_iter(x)).flip())
+    }
+}
+
+impl<L, R, T> Sum<Either<L, R>> for CollectRights<L, T>
+where
+    T: Sum<R>,
+{
+    fn sum<I: Iterator<Item = Either<L, R>>>(iter: I) -> Self {
+        Self(collect_lefts(iter.map(Either::flip), |x| x.sum()).flip())
+    }
+}
+
+impl<L, R, T> Product<Either<L, R>> for CollectRights<L, T>
+where
+    T: Product<R>,
+{
+    fn product<I: Iterator<Item = Either<L, R>>>(iter: I) -> Self {
+        Self(collect_lefts(iter.map(Either::flip), |x| x.product()).flip())
+    }
+}
+
+/// Calls `f` with an iterator of the [`Left`] values of `iter`, which stops
+/// at the first [`Right`] value. Returns the [`Right`] value if any, or the
+/// result of `f` otherwise.
+fn collect_lefts<I, L, R, C, F>(iter: I, f: F) -> Either<C, R>
+where
+    I: Iterator<Item = Either<L, R>>,
+    F: FnOnce(LeftsUntilRight<'_, I, R>) -> C,
+{
+    let mut residual = None;
+    let value = f(LeftsUntilRight {
+        iter,
+        residual: &mut residual,
+    });
+    match residual {
+        Some(x) => Right(x),
+        None => Left(value),
+    }
+}
+
+/// An iterator that yields the [`Left`] values of the underlying iterator,
+/// until the first [`Right`] value, which is stored into `residual`.
+struct LeftsUntilRight<'a, I, R> {
+    iter: I,
+    residual: &'a mut Option<R>,
+}
+
+impl<I, L, R> Iterator for LeftsUntilRight<'_, I, R>
+where
+    I: Iterator<Item = Either<L, R>>,
+{
+    type Item = L;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+        match self.iter.next()? {
+            Left(x) => Some(x),
+            Right(x) => {
+                *self.residual = Some(x);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.residual.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+/// An iterator that is one of the iterators contained in an n-ary sum type,
+/// i.e. [`Either3`] through [`Either8`].
+///
+/// This `struct` can be constructed by
+/// <code>\<[Either3<A, B, C>] as [IntoIterator]\>::into_iter</code>
+/// (which requires all of the contained types implement [`IntoIterator`]),
+/// and similarly for the other n-ary sum types.
+#[derive(Clone, Copy, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIterN<E>(pub(crate) E);
+
+impl<E> IntoIterN<E> {
+    /// Returns the contained n-ary sum type of iterators.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+/// Evaluates the same expression on the contained value of any variant of an
+/// n-ary sum type, like [`for_both!`] does for [`Either`].
+macro_rules! nary_match {
+    ($value:expr, $name:ident { $($V:ident),+ }, $pattern:pat => $result:expr) => {
+        match $value {
+            $($name::$V($pattern) => $result,)+
+        }
+    };
+}
+
+macro_rules! impl_iter_nary {
+    ($name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ }) => {
+        impl<$T0, $($T),+> IntoIterator for $name<$T0, $($T),+>
+        where
+            $T0: IntoIterator,
+            $($T: IntoIterator<Item = $T0::Item>,)+
+        {
+            type Item = $T0::Item;
+            type IntoIter = IntoIterN<$name<$T0::IntoIter, $($T::IntoIter),+>>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIterN(match self {
+                    $name::$V0(x) => $name::$V0(x.into_iter()),
+                    $($name::$V(x) => $name::$V(x.into_iter()),)+
+                })
+            }
+        }
+
+        impl<'a, $T0, $($T),+> IntoIterator for &'a $name<$T0, $($T),+>
+        where
+            &'a $T0: IntoIterator,
+            $(&'a $T: IntoIterator<Item = <&'a $T0 as IntoIterator>::Item>,)+
+        {
+            type Item = <&'a $T0 as IntoIterator>::Item;
+            type IntoIter = IntoIterN<
+                $name<<&'a $T0 as IntoIterator>::IntoIter, $(<&'a $T as IntoIterator>::IntoIter),+>,
+            >;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIterN(match self {
+                    $name::$V0(x) => $name::$V0(x.into_iter()),
+                    $($name::$V(x) => $name::$V(x.into_iter()),)+
+                })
+            }
+        }
+
+        impl<'a, $T0, $($T),+> IntoIterator for &'a mut $name<$T0, $($T),+>
+        where
+            &'a mut $T0: IntoIterator,
+            $(&'a mut $T: IntoIterator<Item = <&'a mut $T0 as IntoIterator>::Item>,)+
+        {
+            type Item = <&'a mut $T0 as IntoIterator>::Item;
+            type IntoIter = IntoIterN<
+                $name<
+                    <&'a mut $T0 as IntoIterator>::IntoIter,
+                    $(<&'a mut $T as IntoIterator>::IntoIter),+
+                >,
+            >;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIterN(match self {
+                    $name::$V0(x) => $name::$V0(x.into_iter()),
+                    $($name::$V(x) => $name::$V(x.into_iter()),)+
+                })
+            }
+        }
+
+        impl<Item, $T0, $($T),+> Extend<Item> for $name<$T0, $($T),+>
+        where
+            $T0: Extend<Item>,
+            $($T: Extend<Item>,)+
+        {
+            fn extend<I: IntoIterator<Item = Item>>(&mut self, iter: I) {
+                match self {
+                    $name::$V0(x) => x.extend(iter),
+                    $($name::$V(x) => x.extend(iter),)+
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> Iterator for IntoIterN<$name<$T0, $($T),+>>
+        where
+            $T0: Iterator,
+            $($T: Iterator<Item = $T0::Item>,)+
+        {
+            type Item = $T0::Item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.next())
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref x => x.size_hint())
+            }
+
+            #[inline]
+            fn count(self) -> usize {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.count())
+            }
+
+            #[inline]
+            fn last(self) -> Option<Self::Item> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.last())
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.nth(n))
+            }
+
+            #[inline]
+            fn fold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
+            where
+                Fold: FnMut(Acc, Self::Item) -> Acc,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.fold(init, f))
+            }
+
+            #[inline]
+            fn for_each<Func>(self, f: Func)
+            where
+                Func: FnMut(Self::Item),
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.for_each(f))
+            }
+
+            #[inline]
+            fn collect<Out: FromIterator<Self::Item>>(self) -> Out {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.collect())
+            }
+
+            #[inline]
+            fn partition<Out, Func>(self, f: Func) -> (Out, Out)
+            where
+                Out: Default + Extend<Self::Item>,
+                Func: FnMut(&Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.partition(f))
+            }
+
+            #[inline]
+            fn all<Func>(&mut self, f: Func) -> bool
+            where
+                Func: FnMut(Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.all(f))
+            }
+
+            #[inline]
+            fn any<Func>(&mut self, f: Func) -> bool
+            where
+                Func: FnMut(Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.any(f))
+            }
+
+            #[inline]
+            fn find<Pred>(&mut self, predicate: Pred) -> Option<Self::Item>
+            where
+                Pred: FnMut(&Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.find(predicate))
+            }
+
+            #[inline]
+            fn find_map<Out, Func>(&mut self, f: Func) -> Option<Out>
+            where
+                Func: FnMut(Self::Item) -> Option<Out>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.find_map(f))
+            }
+
+            #[inline]
+            fn position<Pred>(&mut self, predicate: Pred) -> Option<usize>
+            where
+                Pred: FnMut(Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.position(predicate))
+            }
+
+            #[inline]
+            fn max(self) -> Option<Self::Item>
+            where
+                Self::Item: Ord,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.max())
+            }
+
+            #[inline]
+            fn min(self) -> Option<Self::Item>
+            where
+                Self::Item: Ord,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.min())
+            }
+
+            #[inline]
+            fn max_by_key<Out: Ord, Func>(self, f: Func) -> Option<Self::Item>
+            where
+                Func: FnMut(&Self::Item) -> Out,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.max_by_key(f))
+            }
+
+            #[inline]
+            fn max_by<Func>(self, compare: Func) -> Option<Self::Item>
+            where
+                Func: FnMut(&Self::Item, &Self::Item) -> Ordering,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.max_by(compare))
+            }
+
+            #[inline]
+            fn min_by_key<Out: Ord, Func>(self, f: Func) -> Option<Self::Item>
+            where
+                Func: FnMut(&Self::Item) -> Out,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.min_by_key(f))
+            }
+
+            #[inline]
+            fn min_by<Func>(self, compare: Func) -> Option<Self::Item>
+            where
+                Func: FnMut(&Self::Item, &Self::Item) -> Ordering,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.min_by(compare))
+            }
+
+            #[inline]
+            fn sum<Total>(self) -> Total
+            where
+                Total: Sum<Self::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.sum())
+            }
+
+            #[inline]
+            fn product<Pred>(self) -> Pred
+            where
+                Pred: Product<Self::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.product())
+            }
+
+            #[inline]
+            fn cmp<Other>(self, other: Other) -> Ordering
+            where
+                Other: IntoIterator<Item = Self::Item>,
+                Self::Item: Ord,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.cmp(other))
+            }
+
+            #[inline]
+            fn partial_cmp<Other>(self, other: Other) -> Option<Ordering>
+            where
+                Other: IntoIterator,
+                Self::Item: PartialOrd<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.partial_cmp(other))
+            }
+
+            #[inline]
+            fn eq<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialEq<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.eq(other))
+            }
+
+            #[inline]
+            fn is_sorted_by<Func>(self, compare: Func) -> bool
+            where
+                Func: FnMut(&Self::Item, &Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.is_sorted_by(compare))
+            }
+
+            #[inline]
+            fn is_sorted_by_key<Func, Key>(self, f: Func) -> bool
+            where
+                Func: FnMut(Self::Item) -> Key,
+                Key: PartialOrd,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.is_sorted_by_key(f))
+            }
+
+            #[inline]
+            fn is_sorted(self) -> bool
+            where
+                Self::Item: PartialOrd,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.is_sorted())
+            }
+
+            #[inline]
+            fn reduce<Func>(self, f: Func) -> Option<Self::Item>
+            where
+                Func: FnMut(Self::Item, Self::Item) -> Self::Item,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.reduce(f))
+            }
+
+            #[inline]
+            fn ne<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialEq<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.ne(other))
+            }
+
+            #[inline]
+            fn lt<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialOrd<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.lt(other))
+            }
+
+            #[inline]
+            fn le<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialOrd<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.le(other))
+            }
+
+            #[inline]
+            fn gt<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialOrd<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.gt(other))
+            }
+
+            #[inline]
+            fn ge<Other>(self, other: Other) -> bool
+            where
+                Other: IntoIterator,
+                Self::Item: PartialOrd<Other::Item>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.ge(other))
+            }
+
+            #[cfg(feature = "try_fold")]
+            #[inline]
+            fn try_fold<Acc, Fold, T>(&mut self, init: Acc, f: Fold) -> T
+            where
+                Fold: FnMut(Acc, Self::Item) -> T,
+                T: Try<Output = Acc>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.try_fold(init, f))
+            }
+
+            #[cfg(feature = "advance_by")]
+            #[inline]
+            fn advance_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.advance_by(n))
+            }
+        }
+
+        impl<$T0, $($T),+> DoubleEndedIterator for IntoIterN<$name<$T0, $($T),+>>
+        where
+            $T0: DoubleEndedIterator,
+            $($T: DoubleEndedIterator<Item = $T0::Item>,)+
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.next_back())
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.nth_back(n))
+            }
+
+            #[inline]
+            fn rfold<Acc, Fold>(self, init: Acc, f: Fold) -> Acc
+            where
+                Fold: FnMut(Acc, Self::Item) -> Acc,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, x => x.rfold(init, f))
+            }
+
+            #[inline]
+            fn rfind<Pred>(&mut self, predicate: Pred) -> Option<Self::Item>
+            where
+                Pred: FnMut(&Self::Item) -> bool,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.rfind(predicate))
+            }
+
+            #[cfg(feature = "try_fold")]
+            #[inline]
+            fn try_rfold<Acc, Fold, T>(&mut self, init: Acc, f: Fold) -> T
+            where
+                Fold: FnMut(Acc, Self::Item) -> T,
+                T: Try<Output = Acc>,
+            {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.try_rfold(init, f))
+            }
+
+            #[cfg(feature = "advance_by")]
+            #[inline]
+            fn advance_back_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
+                nary_match!(self.0, $name { $V0 $(, $V)+ }, ref mut x => x.advance_back_by(n))
+            }
+        }
+
+        impl<$T0, $($T),+> ExactSizeIterator for IntoIterN<$name<$T0, $($T),+>>
+        where
+            $T0: ExactSizeIterator,
+            $($T: ExactSizeIterator<Item = $T0::Item>,)+
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                match self.0 {
+                    $name::$V0(ref x) => x.len(),
+                    $($name::$V(ref x) => x.len(),)+
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> FusedIterator for IntoIterN<$name<$T0, $($T),+>>
+        where
+            $T0: FusedIterator,
+            $($T: FusedIterator<Item = $T0::Item>,)+
+        {
+        }
+
+        // SAFETY: `IntoIterN` forwards `size_hint` to the contained iterator,
+        // which reports an accurate length since all of the contained types
+        // implement `TrustedLen`.
+        #[cfg(feature = "trusted_len")]
+        unsafe impl<$T0, $($T),+> TrustedLen for IntoIterN<$name<$T0, $($T),+>>
+        where
+            $T0: TrustedLen,
+            $($T: TrustedLen<Item = $T0::Item>,)+
+        {
+        }
+    };
+}
+
+for_each_nary!(impl_iter_nary);
@@ -0,0 +1,231 @@
+//! This module implements the [`Transparent`] key wrapper of [`Either`],
+//! which hashes and compares through the borrowed view `B` of the contained
+//! value, regardless of the variant.
+//!
+//! The derived [`Hash`], [`Eq`] and [`Ord`] of [`Either`] include the
+//! variant, so `Left(x)` and `Right(x)` are different keys, and neither is
+//! equivalent to the borrowed `x`. Thus [`Either`] does not satisfy the
+//! contract of [`Borrow`] as a map key, and looking up an
+//! `Either<String, Box<str>>` key by [`str`] misses. [`Transparent`]
+//! satisfies the contract, so maps and sets keyed by it can be queried by
+//! the borrowed view, including the [`Equivalent`] lookups of `hashbrown`
+//! and `indexmap`.
+//!
+//! [`Equivalent`]: https://docs.rs/equivalent/latest/equivalent/trait.Equivalent.html
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::key::Transparent;
+//! use std::collections::{BTreeMap, HashMap, HashSet};
+//!
+//! type Key = Transparent<String, Box<str>, str>;
+//!
+//! let mut map = HashMap::new();
+//! map.insert(Key::new(Left("left".to_owned())), 1);
+//! map.insert(Key::new(Right("right".into())), 2);
+//! assert_eq!(map.get("left"), Some(&1));
+//! assert_eq!(map.get("right"), Some(&2));
+//! assert_eq!(map.get("missing"), None);
+//!
+//! // The same string in the other variant is the same key.
+//! map.insert(Key::new(Right("left".into())), 3);
+//! assert_eq!(map.len(), 2);
+//! assert_eq!(map["left"], 3);
+//!
+//! let map: BTreeMap<Key, i32> = map.into_iter().collect();
+//! assert_eq!(map.get("left"), Some(&3));
+//! assert_eq!(map.keys().map(|k| &**k).collect::<Vec<_>>(), ["left", "right"]);
+//!
+//! let set: HashSet<Transparent<Vec<u8>, [u8; 2], [u8]>> =
+//!     [Left(vec![1, 2]), Right([3, 4])].into_iter().map(Transparent::new).collect();
+//! assert!(set.contains(&[1, 2][..]));
+//! assert!(set.contains(&[3, 4][..]));
+//! assert!(!set.contains(&[2, 1][..]));
+//! ```
+//!
+//! With `hashbrown` and `indexmap`:
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::key::Transparent;
+//!
+//! type Key = Transparent<String, &'static str, str>;
+//!
+//! let mut map = hashbrown::HashMap::new();
+//! map.insert(Key::new(Left("left".to_owned())), 1);
+//! map.insert(Key::new(Right("right")), 2);
+//! assert_eq!(map.get("left"), Some(&1));
+//! assert_eq!(map.get("right"), Some(&2));
+//!
+//! let map: indexmap::IndexMap<Key, i32> = map.into_iter().collect();
+//! assert!(map.get("left").is_some());
+//! assert_eq!(map.get_index_of("right").map(|i| map[i]), Some(2));
+//! ```
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::Either;
+
+/// A key wrapper of [`Either`], which hashes and compares by the contained
+/// value borrowed as `B`, regardless of the variant.
+///
+/// So [`Transparent`] implements [`Borrow<B>`] as a key of maps and sets
+/// soundly, as long as `L` and `R` implement [`Borrow<B>`] soundly.
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::key::Transparent;
+///
+/// let a: Transparent<String, &str, str> = Transparent::new(Left("key".to_owned()));
+/// let b: Transparent<String, &str, str> = Transparent::new(Right("key"));
+/// assert_eq!(a, b);
+/// assert_ne!(a.0, b.0);
+/// ```
+pub struct Transparent<L, R, B: ?Sized>(pub Either<L, R>, PhantomData<fn(&B)>);
+
+impl<L, R, B: ?Sized> Transparent<L, R, B> {
+    /// Wraps an [`Either`].
+    #[inline]
+    #[must_use]
+    pub const fn new(value: Either<L, R>) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+}
+
+impl<L, R, B: ?Sized> From<Either<L, R>> for Transparent<L, R, B> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, R, B> Borrow<B> for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized,
+{
+    #[inline]
+    fn borrow(&self) -> &B {
+        for_both!(&self.0, x => x.borrow())
+    }
+}
+
+impl<L, R, B> Deref for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized,
+{
+    type Target = B;
+
+    #[inline]
+    fn deref(&self) -> &B {
+        self.borrow()
+    }
+}
+
+impl<L, R, B> Clone for Transparent<L, R, B>
+where
+    L: Clone,
+    R: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<L, R, B> Copy for Transparent<L, R, B>
+where
+    L: Copy,
+    R: Copy,
+    B: ?Sized,
+{
+}
+
+impl<L, R, B> fmt::Debug for Transparent<L, R, B>
+where
+    L: fmt::Debug,
+    R: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Transparent").field(&self.0).finish()
+    }
+}
+
+impl<L, R, B> Hash for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized + Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<L, R, B> PartialEq for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<L, R, B> Eq for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized + Eq,
+{
+}
+
+impl<L, R, B> PartialOrd for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized + PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<L, R, B> Ord for Transparent<L, R, B>
+where
+    L: Borrow<B>,
+    R: Borrow<B>,
+    B: ?Sized + Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
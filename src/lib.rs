@@ -17,35 +17,91 @@
 //! variants [`Left`] and [`Right`] are symmetric and represent values
 //! without preference.
 //!
+//! The [`convert`] module provides the conversions between the two types.
+//!
 //! [representation]: core::result#representation
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "trusted_len", feature(trusted_len))]
+#![cfg_attr(
+    any(feature = "try_fold", feature = "try_trait"),
+    feature(try_trait_v2)
+)]
+#![cfg_attr(feature = "advance_by", feature(iter_advance_by))]
+#![cfg_attr(
+    feature = "error_generic_member_access",
+    feature(error_generic_member_access)
+)]
+#![cfg_attr(
+    feature = "fn_traits",
+    feature(fn_traits, unboxed_closures, tuple_trait)
+)]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2_residual))]
 
 use core::fmt::Debug;
 use core::hint::unreachable_unchecked;
-use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::ops::{ControlFlow, Deref, DerefMut, Index, IndexMut};
 use core::pin::Pin;
 use core::ptr;
 
 use crate::Either::{Left, Right};
 
+pub use crate::either_or_both::EitherOrBoth;
+pub use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
+
+#[macro_use]
+mod macros;
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+pub mod async_io;
+pub mod bias;
 pub mod borrow;
+pub mod call;
 pub mod clone;
+pub mod cmp;
 pub mod convert;
+#[cfg(feature = "std")]
+pub mod cow;
 #[cfg(feature = "serde")]
 pub mod de;
+pub mod either_or_both;
 pub mod error;
 pub mod fmt;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod format;
 pub mod future;
 pub mod hash;
 #[cfg(feature = "std")]
 pub mod io;
 pub mod iter;
+pub mod key;
+pub mod nary;
+#[cfg(feature = "num-traits")]
+pub mod num;
 pub mod ops;
 pub mod option;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod process;
 pub mod result;
 #[cfg(feature = "serde")]
 pub mod ser;
+#[cfg(feature = "serde")]
+pub mod serde_error;
+#[cfg(feature = "serde")]
+pub mod serde_lowercase;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde_tagged;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde_untagged;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde_untagged_optional;
+#[cfg(feature = "futures")]
+pub mod stream;
+#[cfg(feature = "try_trait")]
+pub mod try_trait;
 
 /// The `enum` type with variants [`Left`] and [`Right`] is a general purpose
 /// sum type with two cases.
@@ -692,6 +748,70 @@ impl<L, R> Either<L, R> {
         }
     }
 
+    /// Returns the contained [`Left`] value in [`Continue`],
+    /// otherwise returns the contained [`Right`] value in [`Break`].
+    ///
+    /// So `?` on the result short-circuits on the [`Right`] value, in
+    /// functions returning [`ControlFlow`].
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output        |
+    /// | ---------- | ------------- |
+    /// | `Left(x)`  | `Continue(x)` |
+    /// | `Right(x)` | `Break(x)`    |
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    /// [`Break`]: ControlFlow::Break
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::ops::ControlFlow;
+    ///
+    /// fn sum_until_word(items: &[Either<u32, &'static str>]) -> ControlFlow<&'static str, u32> {
+    ///     let mut sum = 0;
+    ///     for item in items {
+    ///         sum += item.left_flow()?;
+    ///     }
+    ///     ControlFlow::Continue(sum)
+    /// }
+    ///
+    /// assert_eq!(sum_until_word(&[Left(1), Left(2)]), ControlFlow::Continue(3));
+    /// assert_eq!(sum_until_word(&[Left(1), Right("stop"), Left(2)]), ControlFlow::Break("stop"));
+    /// ```
+    #[inline]
+    pub fn left_flow(self) -> ControlFlow<R, L> {
+        match self {
+            Left(x) => ControlFlow::Continue(x),
+            Right(x) => ControlFlow::Break(x),
+        }
+    }
+
+    /// Returns the contained [`Right`] value in [`Continue`],
+    /// otherwise returns the contained [`Left`] value in [`Break`].
+    ///
+    /// So `?` on the result short-circuits on the [`Left`] value, in
+    /// functions returning [`ControlFlow`].
+    ///
+    /// # Result
+    ///
+    /// | Input      | Output        |
+    /// | ---------- | ------------- |
+    /// | `Left(x)`  | `Break(x)`    |
+    /// | `Right(x)` | `Continue(x)` |
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    /// [`Break`]: ControlFlow::Break
+    #[inline]
+    pub fn right_flow(self) -> ControlFlow<L, R> {
+        match self {
+            Left(x) => ControlFlow::Break(x),
+            Right(x) => ControlFlow::Continue(x),
+        }
+    }
+
     /// Returns the contained [`Left`] value, otherwise converts the [`Right`]
     /// value to `L`.
     ///
@@ -1540,72 +1660,71 @@ impl<L, R> Either<L, R> {
         }
     }
 
-    /// Inserts provided `value` into the [`Left`] variant if it is [`Right`],
-    /// then returns a mutable reference to the contained value.
+    /// Converts the [`Right`] value into the [`Left`] variant with function
+    /// `f` if it is [`Right`], then returns a mutable reference to the
+    /// contained value.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted, since the [`Right`] value has
+    /// been moved into `f` and there is no value left in `self`. See
+    /// [`replace_with`](Either::replace_with).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<String, u32> = Right(42);
+    /// x.left_or_insert_with(|n| n.to_string()).push('!');
+    /// assert_eq!(x, Left("42!".to_owned()));
+    /// ```
     #[inline]
     pub fn left_or_insert_with<F>(&mut self, f: F) -> &mut L
     where
         F: FnOnce(R) -> L,
     {
-        match self {
-            Left(x) => x,
-            // SAFETY: This is safe because:
-            // - `self` will be written with some value after it is read.
-            // - after `self` is read and before it is written, only `f(x)` is
-            //   called. And the function `f` would not access to `self` since
-            //   `self` is mutably borrowed.
-            // - the code above `as_right_unchecked_mut` assigned `self` with
-            //   `Right`.
-            Right(x) => unsafe {
-                let x = ptr::read(x);
-                ptr::write(self, Left(f(x)));
-                self.as_left_unchecked_mut()
-            },
-        }
+        self.right_to_left_in_place(f);
+        // SAFETY: the code above just converted `self` into `Left`.
+        unsafe { self.as_left_unchecked_mut() }
     }
 
-    /// Inserts provided `value` into the [`Right`] variant if it is [`Left`],
-    /// then returns a mutable reference to the contained value.
+    /// Converts the [`Left`] value into the [`Right`] variant with function
+    /// `f` if it is [`Left`], then returns a mutable reference to the
+    /// contained value.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted, since the [`Left`] value has
+    /// been moved into `f` and there is no value left in `self`. See
+    /// [`replace_with`](Either::replace_with).
     #[inline]
     pub fn right_or_insert_with<F>(&mut self, f: F) -> &mut R
     where
         F: FnOnce(L) -> R,
     {
-        match self {
-            // SAFETY: This is safe because:
-            // - `self` will be written with some value after it is read.
-            // - after `self` is read and before it is written, only `f(x)` is
-            //   called. And the function `f` would not access to `self` since
-            //   `self` is mutably borrowed.
-            // - the code above `as_right_unchecked_mut` assigned `self` with
-            //   `Right`.
-            Left(x) => unsafe {
-                let x = ptr::read(x);
-                ptr::write(self, Right(f(x)));
-                self.as_right_unchecked_mut()
-            },
-            Right(x) => x,
-        }
+        self.left_to_right_in_place(f);
+        // SAFETY: the code above just converted `self` into `Right`.
+        unsafe { self.as_right_unchecked_mut() }
     }
 
     /// Applies function `f` on the contained [`Left`] value,
     /// assigning `self` with the result of the function.
     ///
     /// NOTE: this function does nothing if `self` is the [`Right`] variant.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted. See
+    /// [`replace_with`](Either::replace_with).
     pub fn left_and_modify<F>(&mut self, f: F) -> &mut Self
     where
         F: FnOnce(L) -> Self,
     {
-        if let Left(x) = self {
-            // SAFETY: This is safe because:
-            // - `self` will be written with some value after it is read.
-            // - After `self` is read and before it is written, only `f(x)` is
-            //   called. And the function `f` would not access to `self` since
-            //   `self` is mutably borrowed.
-            unsafe {
-                let x = ptr::read(x);
-                ptr::write(self, f(x));
-            }
+        if self.is_left() {
+            self.replace_with(|x| match x {
+                Left(x) => f(x),
+                Right(x) => Right(x),
+            });
         }
         self
     }
@@ -1614,23 +1733,263 @@ impl<L, R> Either<L, R> {
     /// assigning `self` with the result of the function.
     ///
     /// NOTE: this function does nothing if `self` is the [`Left`] variant.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted. See
+    /// [`replace_with`](Either::replace_with).
     pub fn right_and_modify<F>(&mut self, f: F) -> &mut Self
     where
         F: FnOnce(R) -> Self,
     {
-        if let Right(x) = self {
-            // SAFETY: This is safe because:
-            // - `self` will be written with some value after it is read.
-            // - After `self` is read and before it is written, only `f(x)` is
-            //   called. And the function `f` would not access to `self` since
-            //   `self` is mutably borrowed.
-            unsafe {
-                let x = ptr::read(x);
-                ptr::write(self, f(x));
-            }
+        if self.is_right() {
+            self.replace_with(|x| match x {
+                Left(x) => Left(x),
+                Right(x) => f(x),
+            });
         }
         self
     }
+
+    /// Converts the [`Left`] value into the [`Right`] variant in place with
+    /// function `f`, returning `true` if `self` was [`Left`].
+    ///
+    /// NOTE: this function does nothing if `self` is the [`Right`] variant.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted. Use
+    /// [`left_to_right_in_place_or_else`] to provide a fallback value instead.
+    ///
+    /// [`left_to_right_in_place_or_else`]: Either::left_to_right_in_place_or_else
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<&str, usize> = Left("four");
+    /// assert!(x.left_to_right_in_place(str::len));
+    /// assert!(!x.left_to_right_in_place(str::len));
+    /// assert_eq!(x, Right(4));
+    /// ```
+    pub fn left_to_right_in_place<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(L) -> R,
+    {
+        self.left_to_right_in_place_or_else(Self::abort_replace, f)
+    }
+
+    /// Converts the [`Left`] value into the [`Right`] variant in place with
+    /// function `f`, returning `true` if `self` was [`Left`].
+    ///
+    /// If `f` panics, `self` is assigned with the result of `fallback` before
+    /// the panic is propagated. See
+    /// [`replace_with_or_else`](Either::replace_with_or_else).
+    ///
+    /// NOTE: this function does nothing if `self` is the [`Right`] variant.
+    pub fn left_to_right_in_place_or_else<D, F>(&mut self, fallback: D, f: F) -> bool
+    where
+        D: FnOnce() -> Self,
+        F: FnOnce(L) -> R,
+    {
+        let is_left = self.is_left();
+        if is_left {
+            self.replace_with_or_else(fallback, |x| Right(x.right_unwrap_or_else(f)));
+        }
+        is_left
+    }
+
+    /// Converts the [`Right`] value into the [`Left`] variant in place with
+    /// function `f`, returning `true` if `self` was [`Right`].
+    ///
+    /// NOTE: this function does nothing if `self` is the [`Left`] variant.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted. Use
+    /// [`right_to_left_in_place_or_else`] to provide a fallback value instead.
+    ///
+    /// [`right_to_left_in_place_or_else`]: Either::right_to_left_in_place_or_else
+    pub fn right_to_left_in_place<F>(&mut self, f: F) -> bool
+    where
+        F: FnOnce(R) -> L,
+    {
+        self.right_to_left_in_place_or_else(Self::abort_replace, f)
+    }
+
+    /// Converts the [`Right`] value into the [`Left`] variant in place with
+    /// function `f`, returning `true` if `self` was [`Right`].
+    ///
+    /// If `f` panics, `self` is assigned with the result of `fallback` before
+    /// the panic is propagated. See
+    /// [`replace_with_or_else`](Either::replace_with_or_else).
+    ///
+    /// NOTE: this function does nothing if `self` is the [`Left`] variant.
+    pub fn right_to_left_in_place_or_else<D, F>(&mut self, fallback: D, f: F) -> bool
+    where
+        D: FnOnce() -> Self,
+        F: FnOnce(R) -> L,
+    {
+        let is_right = self.is_right();
+        if is_right {
+            self.replace_with_or_else(fallback, |x| Left(x.left_unwrap_or_else(f)));
+        }
+        is_right
+    }
+
+    /// Replaces `self` with the result of function `f`, which takes the
+    /// current value by value.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted: the current value has been moved
+    /// into `f`, so there is no value left in `self` to be dropped or used
+    /// during unwinding. Use [`replace_with_or_else`] to provide a fallback
+    /// value instead.
+    ///
+    /// [`replace_with_or_else`]: Either::replace_with_or_else
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<Vec<u8>, String> = Left(b"either".to_vec());
+    /// x.replace_with(|x| match x {
+    ///     Left(bytes) => String::from_utf8(bytes).map_or_else(|e| Left(e.into_bytes()), Right),
+    ///     Right(s) => Right(s),
+    /// });
+    /// assert_eq!(x, Right("either".to_owned()));
+    /// ```
+    pub fn replace_with<F>(&mut self, f: F)
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        self.replace_with_or_else(Self::abort_replace, f);
+    }
+
+    /// Replaces `self` with the result of function `f`, which takes the
+    /// current value by value.
+    ///
+    /// If `f` panics, `self` is assigned with the result of `fallback` before
+    /// the panic is propagated. If `fallback` also panics, the process is
+    /// aborted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use std::panic::{self, AssertUnwindSafe};
+    /// use std::rc::Rc;
+    ///
+    /// let payload = Rc::new(());
+    /// let mut x: Either<Rc<()>, &str> = Left(Rc::clone(&payload));
+    ///
+    /// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+    ///     x.replace_with_or_else(|| Right("fallback"), |_| panic!("oops"));
+    /// }));
+    /// assert!(result.is_err());
+    /// assert_eq!(x, Right("fallback"));
+    ///
+    /// // The payload moved into the closure was dropped exactly once.
+    /// assert_eq!(Rc::strong_count(&payload), 1);
+    /// ```
+    pub fn replace_with_or_else<D, F>(&mut self, fallback: D, f: F)
+    where
+        D: FnOnce() -> Self,
+        F: FnOnce(Self) -> Self,
+    {
+        let mut guard = ReplaceGuard {
+            dest: self,
+            fallback: Some(fallback),
+        };
+        // SAFETY: the value read from `dest` is never dropped in place:
+        // - if `f` returns, `dest` is overwritten with the result without
+        //   dropping, and the guard is disarmed.
+        // - if `f` panics, the guard overwrites `dest` with the fallback value
+        //   without dropping, or aborts the process if `fallback` panics too.
+        unsafe {
+            let value = ptr::read(guard.dest);
+            let value = f(value);
+            let fallback = guard.fallback.take();
+            ptr::write(guard.dest, value);
+            drop(fallback);
+        }
+    }
+
+    /// Replaces `self` with the result of function `f`, which takes the
+    /// current value by value, and may fail by returning a value to restore
+    /// together with the error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`, with `self` assigned with the restored value.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the process is aborted. Use
+    /// [`try_replace_with_or_else`] to provide a fallback value instead.
+    ///
+    /// [`try_replace_with_or_else`]: Either::try_replace_with_or_else
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let mut x: Either<String, u32> = Left("12".to_owned());
+    /// let parse = |x: Either<String, u32>| match x {
+    ///     Left(s) => match s.parse() {
+    ///         Ok(n) => Ok(Right(n)),
+    ///         Err(e) => Err((Left(s), e)),
+    ///     },
+    ///     Right(n) => Ok(Right(n)),
+    /// };
+    /// assert!(x.try_replace_with(parse).is_ok());
+    /// assert_eq!(x, Right(12));
+    ///
+    /// let mut y: Either<String, u32> = Left("twelve".to_owned());
+    /// assert!(y.try_replace_with(parse).is_err());
+    /// assert_eq!(y, Left("twelve".to_owned()));
+    /// ```
+    pub fn try_replace_with<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnOnce(Self) -> Result<Self, (Self, E)>,
+    {
+        self.try_replace_with_or_else(Self::abort_replace, f)
+    }
+
+    /// Replaces `self` with the result of function `f`, which takes the
+    /// current value by value, and may fail by returning a value to restore
+    /// together with the error.
+    ///
+    /// If `f` panics, `self` is assigned with the result of `fallback` before
+    /// the panic is propagated. See
+    /// [`replace_with_or_else`](Either::replace_with_or_else).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f`, with `self` assigned with the restored value.
+    pub fn try_replace_with_or_else<D, E, F>(&mut self, fallback: D, f: F) -> Result<(), E>
+    where
+        D: FnOnce() -> Self,
+        F: FnOnce(Self) -> Result<Self, (Self, E)>,
+    {
+        let mut error = None;
+        self.replace_with_or_else(fallback, |x| match f(x) {
+            Ok(x) => x,
+            Err((x, e)) => {
+                error = Some(e);
+                x
+            }
+        });
+        error.map_or(Ok(()), Err)
+    }
+
+    /// The fallback of the in-place replacements without one, which panics
+    /// while unwinding from the panic of the closure, so the process is
+    /// aborted.
+    fn abort_replace() -> Self {
+        panic!("aborting: a closure replacing `Either` in place panicked")
+    }
 }
 
 impl<L, R> Either<&L, &R> {
@@ -1769,6 +2128,30 @@ impl<L, R> Either<&mut L, &mut R> {
     }
 }
 
+/// The guard of [`Either::replace_with_or_else`], which writes the fallback
+/// value into `dest` when dropped during unwinding.
+struct ReplaceGuard<'a, T, D>
+where
+    D: FnOnce() -> T,
+{
+    dest: &'a mut T,
+    fallback: Option<D>,
+}
+
+impl<T, D> Drop for ReplaceGuard<'_, T, D>
+where
+    D: FnOnce() -> T,
+{
+    fn drop(&mut self) {
+        if let Some(fallback) = self.fallback.take() {
+            // SAFETY: the guard is armed only while the value of `dest` has
+            // been moved out, so it must be overwritten without dropping.
+            // If `fallback` panics during unwinding, the process is aborted.
+            unsafe { ptr::write(self.dest, fallback()) }
+        }
+    }
+}
+
 // This is a separate function to avoid constructing a `dyn Debug`
 // that gets immediately thrown away, since vtables don't get cleaned up
 // by dead code elimination if a trait object is constructed even if it goes
@@ -0,0 +1,180 @@
+//! This module defines the macros for working with [`Either`] uniformly.
+//!
+//! - [`for_both!`] evaluates the same expression on either variant
+//! - [`try_left!`] and [`try_right!`] extract one variant or return early
+//! - [`either!`] constructs an [`Either`] from a boolean condition
+//!
+//! All of the macros refer to [`Either`] through `$crate`, so they work in
+//! `no_std` crates and do not require any import besides the macro itself.
+//!
+//! [`Either`]: crate::Either
+
+/// Evaluates the same expression on the contained [`Left`] or [`Right`]
+/// value, binding the value with the provided pattern.
+///
+/// The pattern may use any binding mode, e.g. `ref x` or `ref mut x`.
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Result
+///
+/// | Input      | Output    |
+/// | ---------- | --------- |
+/// | `Left(x)`  | `body(x)` |
+/// | `Right(x)` | `body(x)` |
+///
+/// # Example
+///
+/// ```
+/// # use either::{for_both, Either::{self, Left, Right}};
+/// let x: Either<String, &str> = Left(String::from("hello"));
+/// assert_eq!(for_both!(x, s => s.len()), 5);
+///
+/// let mut y: Either<Vec<u8>, [u8; 2]> = Right([1, 2]);
+/// for_both!(y, ref mut v => v[0] = 3);
+/// assert_eq!(y, Right([3, 2]));
+/// ```
+#[macro_export]
+macro_rules! for_both {
+    ($value:expr, $pattern:pat => $result:expr $(,)?) => {
+        match $value {
+            $crate::Either::Left($pattern) => $result,
+            $crate::Either::Right($pattern) => $result,
+        }
+    };
+}
+
+/// Extracts the contained [`Left`] value, otherwise returns from the enclosing
+/// function with the [`Right`] value converted by [`From`] and wrapped in
+/// [`Right`].
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Result
+///
+/// | Input      | Output                        |
+/// | ---------- | ----------------------------- |
+/// | `Left(x)`  | `x`                           |
+/// | `Right(x)` | `return Right(From::from(x))` |
+///
+/// # Example
+///
+/// ```
+/// # use either::{try_left, Either::{self, Left, Right}};
+/// fn twice(x: Either<i32, &str>) -> Either<i32, String> {
+///     let x = try_left!(x);
+///     Left(x * 2)
+/// }
+///
+/// assert_eq!(twice(Left(3)), Left(6));
+/// assert_eq!(twice(Right("oops")), Right(String::from("oops")));
+/// ```
+#[macro_export]
+macro_rules! try_left {
+    ($value:expr $(,)?) => {
+        match $value {
+            $crate::Either::Left(x) => x,
+            $crate::Either::Right(x) => {
+                return $crate::Either::Right(::core::convert::From::from(x));
+            }
+        }
+    };
+}
+
+/// Extracts the contained [`Right`] value, otherwise returns from the
+/// enclosing function with the [`Left`] value converted by [`From`] and
+/// wrapped in [`Left`].
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Result
+///
+/// | Input      | Output                        |
+/// | ---------- | ----------------------------- |
+/// | `Left(x)`  | `return Left(From::from(x))`  |
+/// | `Right(x)` | `x`                           |
+///
+/// # Example
+///
+/// ```
+/// # use either::{try_right, Either::{self, Left, Right}};
+/// fn twice(x: Either<&str, i32>) -> Either<String, i32> {
+///     let x = try_right!(x);
+///     Right(x * 2)
+/// }
+///
+/// assert_eq!(twice(Right(3)), Right(6));
+/// assert_eq!(twice(Left("oops")), Left(String::from("oops")));
+/// ```
+#[macro_export]
+macro_rules! try_right {
+    ($value:expr $(,)?) => {
+        match $value {
+            $crate::Either::Left(x) => {
+                return $crate::Either::Left(::core::convert::From::from(x));
+            }
+            $crate::Either::Right(x) => x,
+        }
+    };
+}
+
+/// Returns `left` wrapped in [`Left`] if `condition` is `true`,
+/// otherwise returns `right` wrapped in [`Right`].
+///
+/// Only the selected expression is evaluated.
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Result
+///
+/// | `condition` | Output         |
+/// | ----------- | -------------- |
+/// | `true`      | `Left(left)`   |
+/// | `false`     | `Right(right)` |
+///
+/// # Example
+///
+/// ```
+/// # use either::{either, Either::{self, Left, Right}};
+/// let x: Either<i32, &str> = either!(1 + 1 == 2, 3, "three");
+/// assert_eq!(x, Left(3));
+///
+/// let y: Either<i32, &str> = either!(1 + 1 == 3, 3, "three");
+/// assert_eq!(y, Right("three"));
+/// ```
+#[macro_export]
+macro_rules! either {
+    ($condition:expr, $left:expr, $right:expr $(,)?) => {
+        if $condition {
+            $crate::Either::Left($left)
+        } else {
+            $crate::Either::Right($right)
+        }
+    };
+}
+
+/// Invokes the provided macro once for each of the n-ary sum types, passing
+/// the type name and its variants.
+///
+/// This is used for implementing the forwarding traits for the types defined
+/// in the [`nary`](crate::nary) module.
+macro_rules! for_each_nary {
+    ($callback:ident) => {
+        $callback! { Either3 { First(A), Second(B), Third(C) } }
+        $callback! { Either4 { First(A), Second(B), Third(C), Fourth(D) } }
+        $callback! { Either5 { First(A), Second(B), Third(C), Fourth(D), Fifth(E) } }
+        $callback! { Either6 { First(A), Second(B), Third(C), Fourth(D), Fifth(E), Sixth(F) } }
+        $callback! {
+            Either7 { First(A), Second(B), Third(C), Fourth(D), Fifth(E), Sixth(F), Seventh(G) }
+        }
+        $callback! {
+            Either8 {
+                First(A), Second(B), Third(C), Fourth(D), Fifth(E), Sixth(F), Seventh(G), Eighth(H)
+            }
+        }
+    };
+}
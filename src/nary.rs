@@ -0,0 +1,316 @@
+//! This module defines the n-ary sum types [`Either3`] through [`Either8`],
+//! the generalizations of [`Either`] with three to eight cases.
+//!
+//! The variants are named after their position, from `First` to `Eighth`.
+//!
+//! ```no_run
+//! # #[allow(dead_code)]
+//! enum Either3<A, B, C> {
+//!    First(A),
+//!    Second(B),
+//!    Third(C),
+//! }
+//! ```
+//!
+//! Each type provides the same method shape as [`Either`] (`is_*`, `as_ref`,
+//! `as_mut`, `as_pin_ref`, `as_pin_mut`, `map`, `fold` and `into_inner`), and
+//! the same forwarding trait implementations as [`Either`] in the [`iter`],
+//! `io` (with `std`), [`fmt`], [`future`], [`error`], [`hash`] and [`ops`]
+//! modules.
+//!
+//! ```
+//! # use either::Either4;
+//! let x: Either4<i32, u8, &str, char> = Either4::Third("three");
+//! assert!(x.is_third());
+//!
+//! let len = x.fold(|_| 4, |_| 1, str::len, char::len_utf8);
+//! assert_eq!(len, 5);
+//!
+//! let y = x.map(|x| x, u32::from, |_| 3, |_| 4);
+//! assert_eq!(y, Either4::Third(3));
+//! assert_eq!(Either4::<u8, u8, u8, u8>::Fourth(7).into_inner(), 7);
+//!
+//! let z: Either4<Vec<i32>, [i32; 2], Option<i32>, Vec<i32>> = Either4::Second([1, 2]);
+//! assert_eq!(z.into_iter().sum::<i32>(), 3);
+//! ```
+//!
+//! # Nesting
+//!
+//! The [`From`] trait converts losslessly between an n-ary type and the
+//! right-nested [`Either`], e.g. between `Either4<A, B, C, D>` and
+//! `Either<A, Either<B, Either<C, D>>>`.
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use either::Either3;
+//! let x: Either<i32, Either<char, &str>> = Right(Left('x'));
+//! let y: Either3<i32, char, &str> = x.into();
+//! assert_eq!(y, Either3::Second('x'));
+//! assert_eq!(Either::from(y), x);
+//!
+//! # use either::Either4;
+//! let x: Either<i32, Either<char, Either<&str, u8>>> = Right(Right(Right(4)));
+//! let y: Either4<i32, char, &str, u8> = x.into();
+//! assert_eq!(y, Either4::Fourth(4));
+//! assert_eq!(Either::from(y), x);
+//! ```
+//!
+//! [`iter`]: crate::iter
+//! [`fmt`]: crate::fmt
+//! [`future`]: crate::future
+//! [`error`]: crate::error
+//! [`hash`]: crate::hash
+//! [`ops`]: crate::ops
+
+use core::pin::Pin;
+
+use crate::Either::{self, Left, Right};
+
+/// Expands to the right-nested [`Either`] type of the provided types.
+macro_rules! nested {
+    ($A:ty, $B:ty) => { Either<$A, $B> };
+    ($A:ty, $($rest:ty),+) => { Either<$A, nested!($($rest),+)> };
+}
+
+/// Expands to the second token, discarding the first one.
+macro_rules! replace {
+    ($_discarded:tt $substitute:tt) => {
+        $substitute
+    };
+}
+
+macro_rules! define_either_n {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $prev:ident [$($prev_variant:ident),+],
+        $V0:ident($T0:ident, $is0:ident, $f0:ident: $F0:ident -> $U0:ident)
+        $(, $V:ident($T:ident, $is:ident, $f:ident: $F:ident -> $U:ident))+ $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name<$T0, $($T),+> {
+            #[doc = concat!("Contains a value of type `", stringify!($T0), "`.")]
+            $V0($T0),
+            $(
+                #[doc = concat!("Contains a value of type `", stringify!($T), "`.")]
+                $V($T),
+            )+
+        }
+
+        impl<T> $name<T, $(replace!($T T)),+> {
+            /// Returns the contained value, whichever the variant is.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> T {
+                match self {
+                    Self::$V0(x) $(| Self::$V(x))+ => x,
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> $name<$T0, $($T),+> {
+            #[doc = concat!("Returns `true` if the contained value is [`", stringify!($V0), "`](", stringify!($name), "::", stringify!($V0), ").")]
+            #[inline]
+            #[must_use]
+            pub const fn $is0(&self) -> bool {
+                matches!(self, Self::$V0(_))
+            }
+
+            $(
+                #[doc = concat!("Returns `true` if the contained value is [`", stringify!($V), "`](", stringify!($name), "::", stringify!($V), ").")]
+                #[inline]
+                #[must_use]
+                pub const fn $is(&self) -> bool {
+                    matches!(self, Self::$V(_))
+                }
+            )+
+
+            #[doc = concat!("Converts from `&", stringify!($name), "<..>` to `", stringify!($name), "<&..>`.")]
+            #[inline]
+            #[must_use]
+            pub const fn as_ref(&self) -> $name<&$T0, $(&$T),+> {
+                match self {
+                    Self::$V0(x) => $name::$V0(x),
+                    $(Self::$V(x) => $name::$V(x),)+
+                }
+            }
+
+            #[doc = concat!("Converts from `&mut ", stringify!($name), "<..>` to `", stringify!($name), "<&mut ..>`.")]
+            #[inline]
+            #[must_use]
+            pub const fn as_mut(&mut self) -> $name<&mut $T0, $(&mut $T),+> {
+                match self {
+                    Self::$V0(x) => $name::$V0(x),
+                    $(Self::$V(x) => $name::$V(x),)+
+                }
+            }
+
+            #[doc = concat!("Converts from `Pin<&", stringify!($name), "<..>>` to `", stringify!($name), "<Pin<&..>>`.")]
+            ///
+            /// Also see [`Either::as_pin_ref`].
+            #[inline]
+            #[must_use]
+            pub fn as_pin_ref(self: Pin<&Self>) -> $name<Pin<&$T0>, $(Pin<&$T>),+> {
+                // SAFETY: `x` is guaranteed to be pinned because it comes from
+                // `self` which is pinned.
+                unsafe {
+                    match self.get_ref() {
+                        Self::$V0(x) => $name::$V0(Pin::new_unchecked(x)),
+                        $(Self::$V(x) => $name::$V(Pin::new_unchecked(x)),)+
+                    }
+                }
+            }
+
+            #[doc = concat!("Converts from `Pin<&mut ", stringify!($name), "<..>>` to `", stringify!($name), "<Pin<&mut ..>>`.")]
+            ///
+            /// Also see [`Either::as_pin_mut`].
+            #[inline]
+            #[must_use]
+            pub fn as_pin_mut(self: Pin<&mut Self>) -> $name<Pin<&mut $T0>, $(Pin<&mut $T>),+> {
+                // SAFETY: `get_unchecked_mut` is never used to move the
+                // contained value inside `self`. And `x` is guaranteed to be
+                // pinned because it comes from `self` which is pinned.
+                unsafe {
+                    match self.get_unchecked_mut() {
+                        Self::$V0(x) => $name::$V0(Pin::new_unchecked(x)),
+                        $(Self::$V(x) => $name::$V(Pin::new_unchecked(x)),)+
+                    }
+                }
+            }
+
+            /// Applies the function corresponding to the contained variant,
+            /// returning the result re-wrapped in the same variant.
+            ///
+            /// Also see [`Either::map`].
+            #[inline]
+            #[must_use]
+            #[allow(clippy::too_many_arguments)]
+            pub fn map<$U0, $($U,)+ $F0, $($F),+>(self, $f0: $F0, $($f: $F),+) -> $name<$U0, $($U),+>
+            where
+                $F0: FnOnce($T0) -> $U0,
+                $($F: FnOnce($T) -> $U,)+
+            {
+                match self {
+                    Self::$V0(x) => $name::$V0($f0(x)),
+                    $(Self::$V(x) => $name::$V($f(x)),)+
+                }
+            }
+
+            /// Applies the function corresponding to the contained variant,
+            /// unifying the result.
+            ///
+            /// Also see [`Either::fold`].
+            #[inline]
+            #[must_use]
+            #[allow(clippy::too_many_arguments)]
+            pub fn fold<R, $F0, $($F),+>(self, $f0: $F0, $($f: $F),+) -> R
+            where
+                $F0: FnOnce($T0) -> R,
+                $($F: FnOnce($T) -> R,)+
+            {
+                match self {
+                    Self::$V0(x) => $f0(x),
+                    $(Self::$V(x) => $f(x),)+
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> From<$name<$T0, $($T),+>> for nested!($T0, $($T),+) {
+            #[inline]
+            fn from(value: $name<$T0, $($T),+>) -> Self {
+                match value {
+                    $name::$V0(x) => Left(x),
+                    $($name::$V(x) => Right($prev::$prev_variant(x).into()),)+
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> From<nested!($T0, $($T),+)> for $name<$T0, $($T),+> {
+            #[inline]
+            fn from(value: nested!($T0, $($T),+)) -> Self {
+                match value {
+                    Left(x) => Self::$V0(x),
+                    Right(x) => match $prev::from(x) {
+                        $($prev::$prev_variant(x) => Self::$V(x),)+
+                    },
+                }
+            }
+        }
+    };
+}
+
+define_either_n! {
+    /// A general purpose sum type with three cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either3, Either [Left, Right],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+}
+
+define_either_n! {
+    /// A general purpose sum type with four cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either4, Either3 [First, Second, Third],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+    Fourth(D, is_fourth, d: FD -> TD),
+}
+
+define_either_n! {
+    /// A general purpose sum type with five cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either5, Either4 [First, Second, Third, Fourth],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+    Fourth(D, is_fourth, d: FD -> TD),
+    Fifth(E, is_fifth, e: FE -> TE),
+}
+
+define_either_n! {
+    /// A general purpose sum type with six cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either6, Either5 [First, Second, Third, Fourth, Fifth],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+    Fourth(D, is_fourth, d: FD -> TD),
+    Fifth(E, is_fifth, e: FE -> TE),
+    Sixth(F, is_sixth, f: FF -> TF),
+}
+
+define_either_n! {
+    /// A general purpose sum type with seven cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either7, Either6 [First, Second, Third, Fourth, Fifth, Sixth],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+    Fourth(D, is_fourth, d: FD -> TD),
+    Fifth(E, is_fifth, e: FE -> TE),
+    Sixth(F, is_sixth, f: FF -> TF),
+    Seventh(G, is_seventh, g: FG -> TG),
+}
+
+define_either_n! {
+    /// A general purpose sum type with eight cases.
+    ///
+    /// See the [module-level documentation](self) for more details.
+    Either8, Either7 [First, Second, Third, Fourth, Fifth, Sixth, Seventh],
+    First(A, is_first, a: FA -> TA),
+    Second(B, is_second, b: FB -> TB),
+    Third(C, is_third, c: FC -> TC),
+    Fourth(D, is_fourth, d: FD -> TD),
+    Fifth(E, is_fifth, e: FE -> TE),
+    Sixth(F, is_sixth, f: FF -> TF),
+    Seventh(G, is_seventh, g: FG -> TG),
+    Eighth(H, is_eighth, h: FH -> TH),
+}
@@ -0,0 +1,635 @@
+//! This module implements the traits of the [`num_traits`] crate, with the
+//! `num-traits` feature.
+//!
+//! [`ToPrimitive`] is implemented for [`Either`], if and only if, both `L`
+//! and `R` implement it.
+//!
+//! The other traits require the arithmetic operators between two values of
+//! the same type, and constructors which have to select a variant, e.g.
+//! [`Zero::zero`]. So they are implemented for the wrapper [`Numeric`]
+//! instead, whose type parameter `S` selects the variant of the constructors
+//! by the markers [`LeftBiased`] and [`RightBiased`]:
+//!
+//! - [`Zero`], [`One`] and [`Num`]
+//! - [`Bounded`]
+//! - [`Signed`]
+//! - [`ToPrimitive`], [`FromPrimitive`] and [`NumCast`]
+//! - [`Float`], with the `std` feature
+//!
+//! As the operators of [`Mixed`], the operands of different variants are
+//! combined on the [`Right`] side, after promoting the [`Left`] operand by
+//! the [`Promote`] trait. The comparisons also promote the [`Left`] operand,
+//! so `Left(1.0)` is equal to `Right(1.0)`.
+//!
+//! [`Mixed`]: crate::ops::Mixed
+//! [`RightBiased`]: crate::bias::RightBiased
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::bias::{LeftBiased, RightBiased};
+//! use either::num::Numeric;
+//! use num_traits::{FromPrimitive, Num, ToPrimitive, Zero};
+//!
+//! fn sum<T: Num + Copy>(values: &[T]) -> T {
+//!     values.iter().fold(T::zero(), |acc, &x| acc + x)
+//! }
+//!
+//! type Real = Numeric<f32, f64, LeftBiased>;
+//!
+//! let values = [Real::new(Left(1.5)), Real::new(Left(2.0))];
+//! assert_eq!(sum(&values).0, Left(3.5));
+//!
+//! let values = [Real::new(Left(1.5)), Real::new(Right(2.0))];
+//! assert_eq!(sum(&values).0, Right(3.5));
+//!
+//! assert_eq!(Real::zero().0, Left(0.0));
+//! assert_eq!(Numeric::<f32, f64, RightBiased>::zero().0, Right(0.0));
+//! assert_eq!(Real::from_i64(3).unwrap().0, Left(3.0));
+//! assert_eq!(Real::new(Right(2.5)).to_i32(), Some(2));
+//! assert_eq!(Real::new(Left(1.0)), Real::new(Right(1.0)));
+//! ```
+//!
+//! The [`Float`] methods of two operands follow the same rules.
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::num::Numeric;
+//! use num_traits::Float;
+//!
+//! type Real = Numeric<f32, f64>;
+//!
+//! assert_eq!(Real::new(Left(4.0)).sqrt().0, Left(2.0));
+//! assert_eq!(Real::new(Left(4.0)).powf(Real::new(Right(0.5))).0, Right(2.0));
+//! assert!(Real::nan().0.left().is_some_and(f32::is_nan));
+//! ```
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use core::num::FpCategory;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{Bounded, FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+use crate::Either::{self, Left, Right};
+use crate::bias::{Bias, LeftBiased};
+use crate::ops::Promote;
+
+macro_rules! forward_to_primitive {
+    ($($method:ident -> $T:ty),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(&self) -> Option<$T> {
+                for_both!(self, x => x.$method())
+            }
+        )+
+    };
+    (inner: $($method:ident -> $T:ty),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(&self) -> Option<$T> {
+                self.0.$method()
+            }
+        )+
+    };
+}
+
+impl<L, R> ToPrimitive for Either<L, R>
+where
+    L: ToPrimitive,
+    R: ToPrimitive,
+{
+    forward_to_primitive! {
+        to_isize -> isize,
+        to_i8 -> i8,
+        to_i16 -> i16,
+        to_i32 -> i32,
+        to_i64 -> i64,
+        to_i128 -> i128,
+        to_usize -> usize,
+        to_u8 -> u8,
+        to_u16 -> u16,
+        to_u32 -> u32,
+        to_u64 -> u64,
+        to_u128 -> u128,
+        to_f32 -> f32,
+        to_f64 -> f64,
+    }
+}
+
+/// The wrapper of an [`Either`] implementing the traits of the
+/// [`num_traits`] crate, e.g. a mixed-precision number.
+///
+/// The [`Left`] type is considered narrower than the [`Right`] type, and the
+/// operands of different variants are combined on the [`Right`] side, after
+/// promoting the [`Left`] operand by the [`Promote`] trait. The constructors,
+/// e.g. [`Zero::zero`] and [`FromPrimitive::from_i64`], return the variant
+/// selected by `S`, which is [`LeftBiased`] or [`RightBiased`].
+///
+/// [`RightBiased`]: crate::bias::RightBiased
+///
+/// # Result
+///
+/// | `self`     | `rhs`      | `self op rhs`             |
+/// | ---------- | ---------- | ------------------------- |
+/// | `Left(a)`  | `Left(c)`  | `Left(a op c)`            |
+/// | `Left(a)`  | `Right(d)` | `Right(a.promote() op d)` |
+/// | `Right(b)` | `Left(c)`  | `Right(b op c.promote())` |
+/// | `Right(b)` | `Right(d)` | `Right(b op d)`           |
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::bias::RightBiased;
+/// use either::num::Numeric;
+/// use num_traits::{Bounded, One, Signed};
+///
+/// type Int = Numeric<i32, i64, RightBiased>;
+///
+/// assert_eq!(Int::one().0, Right(1));
+/// assert_eq!(Int::max_value().0, Right(i64::MAX));
+///
+/// let x = Int::new(Left(-7));
+/// assert_eq!(x.abs().0, Left(7));
+/// assert_eq!((x % Int::new(Left(4))).0, Left(-3));
+/// assert_eq!((x * Int::new(Right(1 << 40))).0, Right(-7 << 40));
+/// assert!(x < Int::new(Right(0)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Numeric<L, R, S = LeftBiased>(pub Either<L, R>, PhantomData<S>);
+
+impl<L, R, S> Numeric<L, R, S> {
+    /// Wraps an [`Either`].
+    #[inline]
+    #[must_use]
+    pub const fn new(value: Either<L, R>) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+
+    /// Returns the same value, with the constructors selecting the variant
+    /// by `T` instead.
+    #[inline]
+    #[must_use]
+    pub fn rebias<T>(self) -> Numeric<L, R, T> {
+        Numeric::new(self.0)
+    }
+}
+
+impl<L, R, S> Numeric<L, R, S>
+where
+    L: Promote<R>,
+{
+    /// Returns the value of the [`Right`] type, promoting the [`Left`] value.
+    #[inline]
+    fn promote(self) -> R {
+        match self.0 {
+            Left(x) => x.promote(),
+            Right(x) => x,
+        }
+    }
+
+    /// Combines two values by `f` if both are [`Left`], and by `g` on the
+    /// [`Right`] side otherwise.
+    #[inline]
+    fn zip_with<F, G>(self, other: Self, f: F, g: G) -> Self
+    where
+        F: FnOnce(L, L) -> L,
+        G: FnOnce(R, R) -> R,
+    {
+        Self::new(match (self.0, other.0) {
+            (Left(a), Left(b)) => Left(f(a, b)),
+            (a, b) => Right(g(Self::new(a).promote(), Self::new(b).promote())),
+        })
+    }
+}
+
+impl<L, R, S> Numeric<L, R, S>
+where
+    L: Promote<R> + Clone,
+{
+    /// Compares two values by `f` if both are [`Left`], and by `g` after
+    /// promoting the [`Left`] operand otherwise.
+    #[inline]
+    fn compare_with<T, F, G>(&self, other: &Self, f: F, g: G) -> T
+    where
+        F: FnOnce(&L, &L) -> T,
+        G: FnOnce(&R, &R) -> T,
+    {
+        match (&self.0, &other.0) {
+            (Left(a), Left(b)) => f(a, b),
+            (Left(a), Right(b)) => g(&a.clone().promote(), b),
+            (Right(a), Left(b)) => g(a, &b.clone().promote()),
+            (Right(a), Right(b)) => g(a, b),
+        }
+    }
+}
+
+impl<L, R, S> From<Either<L, R>> for Numeric<L, R, S> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, R, S> PartialEq for Numeric<L, R, S>
+where
+    L: PartialEq + Promote<R> + Clone,
+    R: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.compare_with(other, L::eq, R::eq)
+    }
+}
+
+impl<L, R, S> PartialOrd for Numeric<L, R, S>
+where
+    L: PartialOrd + Promote<R> + Clone,
+    R: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.compare_with(other, L::partial_cmp, R::partial_cmp)
+    }
+}
+
+macro_rules! impl_ops_numeric {
+    ($($Trait:ident $method:ident),+ $(,)?) => {
+        $(
+            impl<L, R, S> $Trait for Numeric<L, R, S>
+            where
+                L: $Trait<Output = L> + Promote<R>,
+                R: $Trait<Output = R>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    self.zip_with(rhs, L::$method, R::$method)
+                }
+            }
+        )+
+    };
+}
+
+impl_ops_numeric!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl<L, R, S> Neg for Numeric<L, R, S>
+where
+    L: Neg<Output = L>,
+    R: Neg<Output = R>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.0.map(L::neg, R::neg))
+    }
+}
+
+impl<L, R, S> Zero for Numeric<L, R, S>
+where
+    L: Zero + Promote<R>,
+    R: Zero,
+    S: Bias,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(S::select(L::zero, R::zero))
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        for_both!(&self.0, x => x.is_zero())
+    }
+}
+
+impl<L, R, S> One for Numeric<L, R, S>
+where
+    L: One + Promote<R>,
+    R: One,
+    S: Bias,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::new(S::select(L::one, R::one))
+    }
+}
+
+impl<L, R, S> Num for Numeric<L, R, S>
+where
+    L: Num + Promote<R> + Clone,
+    R: Num,
+    S: Bias,
+{
+    type FromStrRadixErr = Either<L::FromStrRadixErr, R::FromStrRadixErr>;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let value = S::select(
+            || L::from_str_radix(str, radix),
+            || R::from_str_radix(str, radix),
+        );
+        Result::<Either<L, R>, _>::from(value).map(Self::new)
+    }
+}
+
+impl<L, R, S> Bounded for Numeric<L, R, S>
+where
+    L: Bounded,
+    R: Bounded,
+    S: Bias,
+{
+    #[inline]
+    fn min_value() -> Self {
+        Self::new(S::select(L::min_value, R::min_value))
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::new(S::select(L::max_value, R::max_value))
+    }
+}
+
+impl<L, R, S> Signed for Numeric<L, R, S>
+where
+    L: Signed + Promote<R> + Clone,
+    R: Signed,
+    S: Bias,
+{
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::new(self.0.as_ref().map(L::abs, R::abs))
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self::new(match (&self.0, &other.0) {
+            (Left(a), Left(b)) => Left(a.abs_sub(b)),
+            (Left(a), Right(b)) => Right(a.clone().promote().abs_sub(b)),
+            (Right(a), Left(b)) => Right(a.abs_sub(&b.clone().promote())),
+            (Right(a), Right(b)) => Right(a.abs_sub(b)),
+        })
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Self::new(self.0.as_ref().map(L::signum, R::signum))
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        for_both!(&self.0, x => x.is_positive())
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        for_both!(&self.0, x => x.is_negative())
+    }
+}
+
+impl<L, R, S> ToPrimitive for Numeric<L, R, S>
+where
+    L: ToPrimitive,
+    R: ToPrimitive,
+{
+    forward_to_primitive! {
+        inner:
+        to_isize -> isize,
+        to_i8 -> i8,
+        to_i16 -> i16,
+        to_i32 -> i32,
+        to_i64 -> i64,
+        to_i128 -> i128,
+        to_usize -> usize,
+        to_u8 -> u8,
+        to_u16 -> u16,
+        to_u32 -> u32,
+        to_u64 -> u64,
+        to_u128 -> u128,
+        to_f32 -> f32,
+        to_f64 -> f64,
+    }
+}
+
+macro_rules! forward_from_primitive {
+    ($($method:ident($T:ty)),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(n: $T) -> Option<Self> {
+                let value = S::select(|| L::$method(n), || R::$method(n));
+                Option::<Either<L, R>>::from(value).map(Self::new)
+            }
+        )+
+    };
+}
+
+impl<L, R, S> FromPrimitive for Numeric<L, R, S>
+where
+    L: FromPrimitive,
+    R: FromPrimitive,
+    S: Bias,
+{
+    forward_from_primitive! {
+        from_isize(isize),
+        from_i8(i8),
+        from_i16(i16),
+        from_i32(i32),
+        from_i64(i64),
+        from_i128(i128),
+        from_usize(usize),
+        from_u8(u8),
+        from_u16(u16),
+        from_u32(u32),
+        from_u64(u64),
+        from_u128(u128),
+        from_f32(f32),
+        from_f64(f64),
+    }
+}
+
+impl<L, R, S> NumCast for Numeric<L, R, S>
+where
+    L: NumCast,
+    R: NumCast,
+    S: Bias,
+{
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let value = if S::IS_LEFT {
+            Left(L::from(n)?)
+        } else {
+            Right(R::from(n)?)
+        };
+        Some(Self::new(value))
+    }
+}
+
+#[cfg(feature = "std")]
+macro_rules! float_constructors {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method() -> Self {
+                Self::new(S::select(<L as Float>::$method, <R as Float>::$method))
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! float_predicates {
+    ($($method:ident -> $T:ty),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(self) -> $T {
+                for_both!(self.0, x => Float::$method(x))
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! float_unary {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(self) -> Self {
+                Self::new(self.0.map(<L as Float>::$method, <R as Float>::$method))
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! float_binary {
+    ($($method:ident),+ $(,)?) => {
+        $(
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                self.zip_with(other, <L as Float>::$method, <R as Float>::$method)
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+impl<L, R, S> Float for Numeric<L, R, S>
+where
+    L: Float + Promote<R>,
+    R: Float,
+    S: Bias + Copy,
+{
+    float_constructors! {
+        nan,
+        infinity,
+        neg_infinity,
+        neg_zero,
+        min_value,
+        min_positive_value,
+        epsilon,
+        max_value,
+    }
+
+    float_predicates! {
+        is_nan -> bool,
+        is_infinite -> bool,
+        is_finite -> bool,
+        is_normal -> bool,
+        is_subnormal -> bool,
+        classify -> FpCategory,
+        is_sign_positive -> bool,
+        is_sign_negative -> bool,
+        integer_decode -> (u64, i16, i8),
+    }
+
+    float_unary! {
+        floor,
+        ceil,
+        round,
+        trunc,
+        fract,
+        abs,
+        signum,
+        recip,
+        sqrt,
+        exp,
+        exp2,
+        ln,
+        log2,
+        log10,
+        to_degrees,
+        to_radians,
+        cbrt,
+        sin,
+        cos,
+        tan,
+        asin,
+        acos,
+        atan,
+        exp_m1,
+        ln_1p,
+        sinh,
+        cosh,
+        tanh,
+        asinh,
+        acosh,
+        atanh,
+    }
+
+    float_binary! {
+        powf,
+        log,
+        max,
+        min,
+        abs_sub,
+        hypot,
+        atan2,
+        copysign,
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::new(match (self.0, a.0, b.0) {
+            (Left(x), Left(a), Left(b)) => Left(x.mul_add(a, b)),
+            (x, a, b) => Right(
+                Self::new(x)
+                    .promote()
+                    .mul_add(Self::new(a).promote(), Self::new(b).promote()),
+            ),
+        })
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        Self::new(self.0.map(|x| x.powi(n), |x| x.powi(n)))
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        match self.0 {
+            Left(x) => {
+                let (sin, cos) = x.sin_cos();
+                (Self::new(Left(sin)), Self::new(Left(cos)))
+            }
+            Right(x) => {
+                let (sin, cos) = x.sin_cos();
+                (Self::new(Right(sin)), Self::new(Right(cos)))
+            }
+        }
+    }
+}
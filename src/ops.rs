@@ -16,6 +16,39 @@
 //! - [`BitXor`] and [`BitXorAssign`]
 //!
 //! if and only if, both `L` and `R` implement the corresponding trait.
+//!
+//! The same traits are implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types implement the
+//! corresponding trait.
+//!
+//! # Operators between two `Either`s
+//!
+//! The binary operators above accept any right-hand side type, which is
+//! passed to the contained value as it is. For an [`Either`] on both sides,
+//! wrap the operands in [`Mixed`], which dispatches over the four variant
+//! combinations. The operands of different variants are combined on the
+//! [`Right`] side, after promoting the [`Left`] operand by the [`Promote`]
+//! trait, e.g. from `f32` to `f64`.
+//!
+//! [`Left`]: crate::Either::Left
+//! [`Right`]: crate::Either::Right
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use either::ops::Mixed;
+//!
+//! type Real = Mixed<f32, f64>;
+//!
+//! let x: Real = Mixed(Left(1.5));
+//! let single: Real = Mixed(Left(2.0));
+//! let double: Real = Mixed(Right(2.0));
+//! assert_eq!(x + single, Mixed(Left(3.5)));
+//! assert_eq!(x * double, Mixed(Right(3.0)));
+//!
+//! let mut y: Mixed<i32, i64> = Mixed(Left(7));
+//! y -= Mixed::<i32, i64>(Right(10));
+//! assert_eq!(y, Mixed(Right(-3)));
+//! ```
 
 use core::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
@@ -24,6 +57,7 @@ use core::ops::{
 };
 
 use crate::Either::{self, Left, Right};
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
 
 impl<L, R> Deref for Either<L, R>
 where
@@ -34,10 +68,7 @@ where
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        match self {
-            Left(x) => x,
-            Right(x) => x,
-        }
+        for_both!(self, x => x)
     }
 }
 
@@ -48,10 +79,7 @@ where
 {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        match self {
-            Left(x) => x,
-            Right(x) => x,
-        }
+        for_both!(self, x => x)
     }
 }
 
@@ -64,10 +92,7 @@ where
 
     #[inline]
     fn index(&self, index: Idx) -> &Self::Output {
-        match self {
-            Left(x) => x.index(index),
-            Right(x) => x.index(index),
-        }
+        for_both!(self, x => x.index(index))
     }
 }
 
@@ -78,10 +103,7 @@ where
 {
     #[inline]
     fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
-        match self {
-            Left(x) => x.index_mut(index),
-            Right(x) => x.index_mut(index),
-        }
+        for_both!(self, x => x.index_mut(index))
     }
 }
 
@@ -94,10 +116,7 @@ where
 
     #[inline]
     fn neg(self) -> Self::Output {
-        match self {
-            Left(x) => x.neg(),
-            Right(x) => x.neg(),
-        }
+        for_both!(self, x => x.neg())
     }
 }
 
@@ -110,10 +129,7 @@ where
 
     #[inline]
     fn not(self) -> Self::Output {
-        match self {
-            Left(x) => x.not(),
-            Right(x) => x.not(),
-        }
+        for_both!(self, x => x.not())
     }
 }
 
@@ -126,10 +142,7 @@ where
 
     #[inline]
     fn add(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.add(rhs),
-            Right(x) => x.add(rhs),
-        }
+        for_both!(self, x => x.add(rhs))
     }
 }
 
@@ -142,10 +155,7 @@ where
 
     #[inline]
     fn sub(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.sub(rhs),
-            Right(x) => x.sub(rhs),
-        }
+        for_both!(self, x => x.sub(rhs))
     }
 }
 
@@ -158,10 +168,7 @@ where
 
     #[inline]
     fn mul(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.mul(rhs),
-            Right(x) => x.mul(rhs),
-        }
+        for_both!(self, x => x.mul(rhs))
     }
 }
 
@@ -174,10 +181,7 @@ where
 
     #[inline]
     fn div(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.div(rhs),
-            Right(x) => x.div(rhs),
-        }
+        for_both!(self, x => x.div(rhs))
     }
 }
 
@@ -190,10 +194,7 @@ where
 
     #[inline]
     fn rem(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.rem(rhs),
-            Right(x) => x.rem(rhs),
-        }
+        for_both!(self, x => x.rem(rhs))
     }
 }
 
@@ -206,10 +207,7 @@ where
 
     #[inline]
     fn shl(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.shl(rhs),
-            Right(x) => x.shl(rhs),
-        }
+        for_both!(self, x => x.shl(rhs))
     }
 }
 
@@ -222,10 +220,7 @@ where
 
     #[inline]
     fn shr(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.shr(rhs),
-            Right(x) => x.shr(rhs),
-        }
+        for_both!(self, x => x.shr(rhs))
     }
 }
 
@@ -238,10 +233,7 @@ where
 
     #[inline]
     fn bitand(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.bitand(rhs),
-            Right(x) => x.bitand(rhs),
-        }
+        for_both!(self, x => x.bitand(rhs))
     }
 }
 
@@ -254,10 +246,7 @@ where
 
     #[inline]
     fn bitor(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.bitor(rhs),
-            Right(x) => x.bitor(rhs),
-        }
+        for_both!(self, x => x.bitor(rhs))
     }
 }
 
@@ -270,10 +259,7 @@ where
 
     #[inline]
     fn bitxor(self, rhs: T) -> Self::Output {
-        match self {
-            Left(x) => x.bitxor(rhs),
-            Right(x) => x.bitxor(rhs),
-        }
+        for_both!(self, x => x.bitxor(rhs))
     }
 }
 
@@ -284,10 +270,7 @@ where
 {
     #[inline]
     fn add_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.add_assign(rhs),
-            Right(x) => x.add_assign(rhs),
-        }
+        for_both!(self, x => x.add_assign(rhs))
     }
 }
 
@@ -298,10 +281,7 @@ where
 {
     #[inline]
     fn sub_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.sub_assign(rhs),
-            Right(x) => x.sub_assign(rhs),
-        }
+        for_both!(self, x => x.sub_assign(rhs))
     }
 }
 
@@ -312,10 +292,7 @@ where
 {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.mul_assign(rhs),
-            Right(x) => x.mul_assign(rhs),
-        }
+        for_both!(self, x => x.mul_assign(rhs))
     }
 }
 
@@ -326,10 +303,7 @@ where
 {
     #[inline]
     fn div_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.div_assign(rhs),
-            Right(x) => x.div_assign(rhs),
-        }
+        for_both!(self, x => x.div_assign(rhs))
     }
 }
 
@@ -340,10 +314,7 @@ where
 {
     #[inline]
     fn rem_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.rem_assign(rhs),
-            Right(x) => x.rem_assign(rhs),
-        }
+        for_both!(self, x => x.rem_assign(rhs))
     }
 }
 
@@ -354,10 +325,7 @@ where
 {
     #[inline]
     fn shl_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.shl_assign(rhs),
-            Right(x) => x.shl_assign(rhs),
-        }
+        for_both!(self, x => x.shl_assign(rhs))
     }
 }
 
@@ -368,10 +336,7 @@ where
 {
     #[inline]
     fn shr_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.shr_assign(rhs),
-            Right(x) => x.shr_assign(rhs),
-        }
+        for_both!(self, x => x.shr_assign(rhs))
     }
 }
 
@@ -382,10 +347,7 @@ where
 {
     #[inline]
     fn bitand_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.bitand_assign(rhs),
-            Right(x) => x.bitand_assign(rhs),
-        }
+        for_both!(self, x => x.bitand_assign(rhs))
     }
 }
 
@@ -396,10 +358,7 @@ where
 {
     #[inline]
     fn bitor_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.bitor_assign(rhs),
-            Right(x) => x.bitor_assign(rhs),
-        }
+        for_both!(self, x => x.bitor_assign(rhs))
     }
 }
 
@@ -410,9 +369,308 @@ where
 {
     #[inline]
     fn bitxor_assign(&mut self, rhs: T) {
-        match self {
-            Left(x) => x.bitxor_assign(rhs),
-            Right(x) => x.bitxor_assign(rhs),
+        for_both!(self, x => x.bitxor_assign(rhs))
+    }
+}
+
+macro_rules! impl_ops_nary {
+    ($name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ }) => {
+        impl<$T0, $($T),+> Deref for $name<$T0, $($T),+>
+        where
+            $T0: Deref,
+            $($T: Deref<Target = $T0::Target>,)+
+        {
+            type Target = $T0::Target;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                match self {
+                    $name::$V0(x) => x,
+                    $($name::$V(x) => x,)+
+                }
+            }
+        }
+
+        impl<$T0, $($T),+> DerefMut for $name<$T0, $($T),+>
+        where
+            $T0: DerefMut,
+            $($T: DerefMut<Target = $T0::Target>,)+
+        {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                match self {
+                    $name::$V0(x) => x,
+                    $($name::$V(x) => x,)+
+                }
+            }
+        }
+
+        impl<$T0, $($T,)+ Idx> Index<Idx> for $name<$T0, $($T),+>
+        where
+            $T0: Index<Idx>,
+            $($T: Index<Idx, Output = $T0::Output>,)+
+        {
+            type Output = $T0::Output;
+
+            #[inline]
+            fn index(&self, index: Idx) -> &Self::Output {
+                match self {
+                    $name::$V0(x) => x.index(index),
+                    $($name::$V(x) => x.index(index),)+
+                }
+            }
         }
+
+        impl<$T0, $($T,)+ Idx> IndexMut<Idx> for $name<$T0, $($T),+>
+        where
+            $T0: IndexMut<Idx>,
+            $($T: IndexMut<Idx, Output = $T0::Output>,)+
+        {
+            #[inline]
+            fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+                match self {
+                    $name::$V0(x) => x.index_mut(index),
+                    $($name::$V(x) => x.index_mut(index),)+
+                }
+            }
+        }
+
+        impl_ops_nary!(@unary $name { $V0($T0) $(, $V($T))+ } Neg neg);
+        impl_ops_nary!(@unary $name { $V0($T0) $(, $V($T))+ } Not not);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Add add);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Sub sub);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Mul mul);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Div div);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Rem rem);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Shl shl);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } Shr shr);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } BitAnd bitand);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } BitOr bitor);
+        impl_ops_nary!(@binary $name { $V0($T0) $(, $V($T))+ } BitXor bitxor);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } AddAssign add_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } SubAssign sub_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } MulAssign mul_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } DivAssign div_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } RemAssign rem_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } ShlAssign shl_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } ShrAssign shr_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } BitAndAssign bitand_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } BitOrAssign bitor_assign);
+        impl_ops_nary!(@assign $name { $V0($T0) $(, $V($T))+ } BitXorAssign bitxor_assign);
+    };
+    (@unary $name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ } $Trait:ident $method:ident) => {
+        impl<$T0, $($T),+> $Trait for $name<$T0, $($T),+>
+        where
+            $T0: $Trait,
+            $($T: $Trait<Output = $T0::Output>,)+
+        {
+            type Output = $T0::Output;
+
+            #[inline]
+            fn $method(self) -> Self::Output {
+                match self {
+                    $name::$V0(x) => x.$method(),
+                    $($name::$V(x) => x.$method(),)+
+                }
+            }
+        }
+    };
+    (@binary $name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ } $Trait:ident $method:ident) => {
+        impl<$T0, $($T,)+ Rhs> $Trait<Rhs> for $name<$T0, $($T),+>
+        where
+            $T0: $Trait<Rhs>,
+            $($T: $Trait<Rhs, Output = $T0::Output>,)+
+        {
+            type Output = $T0::Output;
+
+            #[inline]
+            fn $method(self, rhs: Rhs) -> Self::Output {
+                match self {
+                    $name::$V0(x) => x.$method(rhs),
+                    $($name::$V(x) => x.$method(rhs),)+
+                }
+            }
+        }
+    };
+    (@assign $name:ident { $V0:ident($T0:ident) $(, $V:ident($T:ident))+ } $Trait:ident $method:ident) => {
+        impl<$T0, $($T,)+ Rhs> $Trait<Rhs> for $name<$T0, $($T),+>
+        where
+            $T0: $Trait<Rhs>,
+            $($T: $Trait<Rhs>,)+
+        {
+            #[inline]
+            fn $method(&mut self, rhs: Rhs) {
+                match self {
+                    $name::$V0(x) => x.$method(rhs),
+                    $($name::$V(x) => x.$method(rhs),)+
+                }
+            }
+        }
+    };
+}
+
+for_each_nary!(impl_ops_nary);
+
+/// The conversion of a value into a wider type, used by the operators of
+/// [`Mixed`] to combine operands of different variants, and by
+/// [`Either::cmp_by_value`] and [`ByValue`] to compare them.
+///
+/// This trait is implemented for the lossless conversions between the
+/// primitive numeric types, e.g. from `f32` to `f64`, and may be implemented
+/// for other types, e.g. from `i64` to a big integer type.
+///
+/// The comparisons by value are a total order only if the conversion
+/// preserves the order and is injective: `a.promote().cmp(&b.promote())`
+/// must equal `a.cmp(&b)` for all values `a` and `b` of the narrower type.
+/// The implementations for the primitive types satisfy this; a lossy or
+/// saturating conversion does not, and makes the [`Ord`] of [`ByValue`]
+/// inconsistent.
+///
+/// [`ByValue`]: crate::cmp::ByValue
+pub trait Promote<T> {
+    /// Converts `self` into the wider type `T`.
+    fn promote(self) -> T;
+}
+
+macro_rules! impl_promote {
+    ($($from:ty => [$($to:ty),+]),+ $(,)?) => {
+        $($(
+            impl Promote<$to> for $from {
+                #[inline]
+                fn promote(self) -> $to {
+                    <$to>::from(self)
+                }
+            }
+        )+)+
+    };
+}
+
+impl_promote! {
+    u8 => [u16, u32, u64, u128, i16, i32, i64, i128, f32, f64],
+    u16 => [u32, u64, u128, i32, i64, i128, f32, f64],
+    u32 => [u64, u128, i64, i128, f64],
+    u64 => [u128, i128],
+    i8 => [i16, i32, i64, i128, f32, f64],
+    i16 => [i32, i64, i128, f32, f64],
+    i32 => [i64, i128, f64],
+    i64 => [i128],
+    f32 => [f64],
+}
+
+/// The wrapper of an [`Either`] whose operators accept another [`Mixed`] on
+/// the right-hand side, e.g. a mixed-precision number.
+///
+/// The [`Left`] type is considered narrower than the [`Right`] type. The
+/// operands of the same variant are combined as they are, while the operands
+/// of different variants are combined on the [`Right`] side, after promoting
+/// the [`Left`] operand by the [`Promote`] trait.
+///
+/// # Result
+///
+/// | `self`     | `rhs`      | `self op rhs`                   |
+/// | ---------- | ---------- | ------------------------------- |
+/// | `Left(a)`  | `Left(c)`  | `Left(a op c)`                  |
+/// | `Left(a)`  | `Right(d)` | `Right(a.promote() op d)`       |
+/// | `Right(b)` | `Left(c)`  | `Right(b op c.promote())`       |
+/// | `Right(b)` | `Right(d)` | `Right(b op d)`                 |
+///
+/// The assignment operators follow the same table. When `self` is promoted,
+/// the [`Left`] value is cloned before promoting it.
+///
+/// [`Left`]: crate::Either::Left
+/// [`Right`]: crate::Either::Right
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::ops::Mixed;
+///
+/// type Int = Mixed<u8, u32>;
+///
+/// let x: Int = Mixed(Left(200));
+/// assert_eq!(x & Int::from(Left(0x0f)), Mixed(Left(0x08)));
+/// assert_eq!(x + Int::from(Right(100)), Mixed(Right(300)));
+/// assert_eq!(Int::from(Right(1)) << Int::from(Left(4)), Mixed(Right(16)));
+///
+/// let mut y: Mixed<f32, f64> = Mixed(Right(1.0));
+/// y /= Mixed::<f32, f64>(Left(4.0));
+/// assert_eq!(y.into_inner(), Right(0.25));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mixed<L, R>(pub Either<L, R>);
+
+impl<L, R> Mixed<L, R> {
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
     }
 }
+
+impl<L, R> From<Either<L, R>> for Mixed<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self(value)
+    }
+}
+
+macro_rules! impl_ops_mixed {
+    ($($Trait:ident $method:ident, $AssignTrait:ident $assign_method:ident;)+) => {
+        $(
+            impl<A, B, C, D> $Trait<Mixed<C, D>> for Mixed<A, B>
+            where
+                A: $Trait<C> + Promote<B>,
+                B: $Trait<D>,
+                C: Promote<D>,
+            {
+                type Output = Mixed<A::Output, B::Output>;
+
+                #[inline]
+                fn $method(self, rhs: Mixed<C, D>) -> Self::Output {
+                    Mixed(match (self.0, rhs.0) {
+                        (Left(a), Left(c)) => Left(a.$method(c)),
+                        (Left(a), Right(d)) => Right(a.promote().$method(d)),
+                        (Right(b), Left(c)) => Right(b.$method(c.promote())),
+                        (Right(b), Right(d)) => Right(b.$method(d)),
+                    })
+                }
+            }
+
+            impl<A, B, C, D> $AssignTrait<Mixed<C, D>> for Mixed<A, B>
+            where
+                A: $AssignTrait<C> + Promote<B> + Clone,
+                B: $AssignTrait<D>,
+                C: Promote<D>,
+            {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Mixed<C, D>) {
+                    match (&mut self.0, rhs.0) {
+                        (Left(a), Left(c)) => a.$assign_method(c),
+                        (Left(a), Right(d)) => {
+                            let mut b = a.clone().promote();
+                            b.$assign_method(d);
+                            self.0 = Right(b);
+                        }
+                        (Right(b), Left(c)) => b.$assign_method(c.promote()),
+                        (Right(b), Right(d)) => b.$assign_method(d),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_ops_mixed! {
+    Add add, AddAssign add_assign;
+    Sub sub, SubAssign sub_assign;
+    Mul mul, MulAssign mul_assign;
+    Div div, DivAssign div_assign;
+    Rem rem, RemAssign rem_assign;
+    Shl shl, ShlAssign shl_assign;
+    Shr shr, ShrAssign shr_assign;
+    BitAnd bitand, BitAndAssign bitand_assign;
+    BitOr bitor, BitOrAssign bitor_assign;
+    BitXor bitxor, BitXorAssign bitxor_assign;
+}
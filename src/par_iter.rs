@@ -0,0 +1,153 @@
+//! This module implements the traits of the [`rayon`] crate, with the `rayon`
+//! feature:
+//!
+//! - [`ParallelIterator`], if and only if, both `L` and `R` are
+//!   [`ParallelIterator`]s of the same item type
+//! - [`IndexedParallelIterator`], if and only if, both `L` and `R` are
+//!   [`IndexedParallelIterator`]s of the same item type
+//! - [`ParallelExtend`], if and only if, both `L` and `R` implement it
+//!
+//! So the choice between two parallel iterators, or between a parallel and a
+//! sequential path, can be made at runtime without boxing.
+//!
+//! The [`EitherParIterExt`] trait extends every [`ParallelIterator`] with
+//! [`par_partition_map`], which splits the items into two collections.
+//!
+//! [`par_partition_map`]: EitherParIterExt::par_partition_map
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, Once, ParallelIterator};
+//!
+//! fn squares(n: u64, parallel: bool) -> Either<impl ParallelIterator<Item = u64>, Once<u64>> {
+//!     if parallel {
+//!         Left((0..n).into_par_iter().map(|x| x * x))
+//!     } else {
+//!         Right(rayon::iter::once((0..n).map(|x| x * x).sum()))
+//!     }
+//! }
+//!
+//! assert_eq!(squares(100, true).sum::<u64>(), 328350);
+//! assert_eq!(squares(100, false).sum::<u64>(), 328350);
+//!
+//! let x: Either<rayon::range::Iter<u32>, rayon::vec::IntoIter<u32>> =
+//!     Right(vec![3, 4].into_par_iter());
+//! assert_eq!(x.len(), 2);
+//! assert_eq!(x.zip([1, 2]).map(|(a, b)| a * b).collect::<Vec<_>>(), [3, 8]);
+//! ```
+
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator,
+};
+
+use crate::Either::{self, Left, Right};
+
+impl<L, R> ParallelIterator for Either<L, R>
+where
+    L: ParallelIterator,
+    R: ParallelIterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        for_both!(self, x => x.drive_unindexed(consumer))
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        for_both!(self, x => x.opt_len())
+    }
+}
+
+impl<L, R> IndexedParallelIterator for Either<L, R>
+where
+    L: IndexedParallelIterator,
+    R: IndexedParallelIterator<Item = L::Item>,
+{
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        for_both!(self, x => x.drive(consumer))
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        for_both!(self, x => x.len())
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        for_both!(self, x => x.with_producer(callback))
+    }
+}
+
+impl<L, R, T> ParallelExtend<T> for Either<L, R>
+where
+    L: ParallelExtend<T>,
+    R: ParallelExtend<T>,
+    T: Send,
+{
+    #[inline]
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        for_both!(self, x => x.par_extend(par_iter));
+    }
+}
+
+/// An extension trait for [`ParallelIterator`]s, providing adapters for
+/// working with [`Either`] items.
+///
+/// This trait is implemented for every [`ParallelIterator`].
+///
+/// See the [module-level documentation](self) for more details.
+pub trait EitherParIterExt: ParallelIterator {
+    /// Consumes the parallel iterator, mapping each item into an [`Either`]
+    /// with function `f`, then collecting the [`Left`] values into `A` and
+    /// the [`Right`] values into `B`.
+    ///
+    /// This is the parallel version of
+    /// [`EitherIterExt::partition_map`](crate::iter::EitherIterExt::partition_map).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{Left, Right};
+    /// use either::par_iter::EitherParIterExt;
+    /// use rayon::iter::IntoParallelIterator;
+    ///
+    /// let (even, odd): (Vec<i32>, Vec<i32>) = (1..=5)
+    ///     .into_par_iter()
+    ///     .par_partition_map(|x| if x % 2 == 0 { Left(x) } else { Right(x) });
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// ```
+    fn par_partition_map<L, R, A, B, F>(self, f: F) -> (A, B)
+    where
+        A: Default + Send + ParallelExtend<L>,
+        B: Default + Send + ParallelExtend<R>,
+        F: Fn(Self::Item) -> Either<L, R> + Sync + Send,
+        L: Send,
+        R: Send,
+    {
+        self.partition_map(move |x| match f(x) {
+            Left(x) => rayon::iter::Either::Left(x),
+            Right(x) => rayon::iter::Either::Right(x),
+        })
+    }
+}
+
+impl<I: ParallelIterator> EitherParIterExt for I {}
@@ -0,0 +1,161 @@
+//! This module implements the [`FromStr`] trait for [`Either`],
+//! if and only if, both `L` and `R` implement [`FromStr`].
+//!
+//! The string is parsed as `L` first, and as `R` if that fails. If neither
+//! succeeds, both errors are returned in an [`EitherParseError`].
+//!
+//! | `L::from_str(s)` | `R::from_str(s)` | Output                                |
+//! | ---------------- | ---------------- | ------------------------------------- |
+//! | `Ok(x)`          | not called       | `Ok(Left(x))`                         |
+//! | `Err(e)`         | `Ok(y)`          | `Ok(Right(y))`                        |
+//! | `Err(e)`         | `Err(f)`         | `Err(EitherParseError { left: e, right: f })` |
+//!
+//! [`Either::parse_right_first`] tries the other order, and [`TryFrom<&str>`]
+//! is implemented in the same way as [`FromStr`].
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use core::str::FromStr;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Auto;
+//!
+//! impl FromStr for Auto {
+//!     type Err = &'static str;
+//!
+//!     fn from_str(s: &str) -> Result<Self, Self::Err> {
+//!         if s == "auto" { Ok(Auto) } else { Err("expected `auto`") }
+//!     }
+//! }
+//!
+//! let threads: Either<Auto, u32> = "auto".parse().unwrap();
+//! assert_eq!(threads, Left(Auto));
+//!
+//! let threads: Either<Auto, u32> = "8".parse().unwrap();
+//! assert_eq!(threads, Right(8));
+//!
+//! let err = "many".parse::<Either<Auto, u32>>().unwrap_err();
+//! assert_eq!(err.left, "expected `auto`");
+//! assert_eq!(err.to_string(), "expected `auto`, or invalid digit found in string");
+//! ```
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::Either::{self, Left, Right};
+
+/// The error returned when a string can be parsed as neither `L` nor `R`,
+/// containing the errors of both sides.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EitherParseError<L, R> {
+    /// The error of parsing the left type.
+    pub left: L,
+    /// The error of parsing the right type.
+    pub right: R,
+}
+
+impl<L, R> EitherParseError<L, R> {
+    /// Returns the errors of both sides as a tuple.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (L, R) {
+        (self.left, self.right)
+    }
+}
+
+impl<L, R> Display for EitherParseError<L, R>
+where
+    L: Display,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, or {}", self.left, self.right)
+    }
+}
+
+impl<L, R> Error for EitherParseError<L, R>
+where
+    L: Error,
+    R: Error,
+{
+}
+
+impl<L, R> FromStr for Either<L, R>
+where
+    L: FromStr,
+    R: FromStr,
+{
+    type Err = EitherParseError<L::Err, R::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let left = match L::from_str(s) {
+            Ok(x) => return Ok(Left(x)),
+            Err(e) => e,
+        };
+        match R::from_str(s) {
+            Ok(x) => Ok(Right(x)),
+            Err(right) => Err(EitherParseError { left, right }),
+        }
+    }
+}
+
+impl<L, R> TryFrom<&str> for Either<L, R>
+where
+    L: FromStr,
+    R: FromStr,
+{
+    type Error = EitherParseError<L::Err, R::Err>;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str(value)
+    }
+}
+
+impl<L, R> Either<L, R>
+where
+    L: FromStr,
+    R: FromStr,
+{
+    /// Parses `s` as `R` first, and as `L` if that fails.
+    ///
+    /// This is the mirror of [`FromStr::from_str`], useful when both types
+    /// may accept the same string, and `R` should take precedence.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of both sides if `s` can be parsed as neither `L`
+    /// nor `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// let x: Either<u64, u8> = "7".parse().unwrap();
+    /// assert_eq!(x, Left(7));
+    ///
+    /// let x = Either::<u64, u8>::parse_right_first("7").unwrap();
+    /// assert_eq!(x, Right(7));
+    ///
+    /// let x = Either::<u64, u8>::parse_right_first("300").unwrap();
+    /// assert_eq!(x, Left(300));
+    ///
+    /// let x = Either::<u64, u8>::try_from("-1").unwrap_err();
+    /// assert_eq!(x.left, x.right);
+    /// ```
+    pub fn parse_right_first(s: &str) -> Result<Self, EitherParseError<L::Err, R::Err>> {
+        let right = match R::from_str(s) {
+            Ok(x) => return Ok(Right(x)),
+            Err(e) => e,
+        };
+        match L::from_str(s) {
+            Ok(x) => Ok(Left(x)),
+            Err(left) => Err(EitherParseError { left, right }),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! This module implements the [`Termination`] trait for [`Either`],
+//! if and only if, both `L` and `R` implement [`Termination`], so `main` may
+//! return one of two outcome types.
+//!
+//! The same trait is implemented for the n-ary sum types [`Either3`] through
+//! [`Either8`], if and only if, all of the contained types implement
+//! [`Termination`].
+//!
+//! [`Either::exit_with`] exits the process with an exit code chosen by the
+//! variant instead.
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! use std::process::ExitCode;
+//!
+//! fn main() -> Either<ExitCode, Result<(), String>> {
+//!     let args: Vec<String> = std::env::args().skip(1).collect();
+//!     if args.iter().any(|arg| arg == "--help") {
+//!         return Left(ExitCode::SUCCESS);
+//!     }
+//!     Right(Ok(()))
+//! }
+//! ```
+
+use std::process::{self, ExitCode, Termination};
+
+use crate::Either;
+use crate::nary::{Either3, Either4, Either5, Either6, Either7, Either8};
+
+impl<L, R> Termination for Either<L, R>
+where
+    L: Termination,
+    R: Termination,
+{
+    #[inline]
+    fn report(self) -> ExitCode {
+        for_both!(self, x => x.report())
+    }
+}
+
+macro_rules! impl_termination_nary {
+    ($name:ident { $($V:ident($T:ident)),+ }) => {
+        impl<$($T),+> Termination for $name<$($T),+>
+        where
+            $($T: Termination,)+
+        {
+            #[inline]
+            fn report(self) -> ExitCode {
+                match self {
+                    $($name::$V(x) => x.report(),)+
+                }
+            }
+        }
+    };
+}
+
+for_each_nary!(impl_termination_nary);
+
+impl<L, R> Either<L, R> {
+    /// Exits the process with `left_code` if `self` is [`Left`], or with
+    /// `right_code` if `self` is [`Right`].
+    ///
+    /// The contained value is dropped before exiting, but the destructors of
+    /// the other values on the stack are not run, as with
+    /// [`std::process::exit`].
+    ///
+    /// [`Left`]: crate::Either::Left
+    /// [`Right`]: crate::Either::Right
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use either::Either::{self, Left, Right};
+    /// let outcome: Either<(), String> = Right("no input files".to_owned());
+    /// outcome.exit_with(0, 2);
+    /// ```
+    pub fn exit_with(self, left_code: i32, right_code: i32) -> ! {
+        let code = if self.is_left() {
+            left_code
+        } else {
+            right_code
+        };
+        drop(self);
+        process::exit(code)
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! That means, `Either<L, R>` is serializer, if and only if, both `L` and `R`
 //! are serializers.
+//!
+//! The error type is [`EitherError`], whose side follows the active
+//! serializer. See the [`serde_error`](crate::serde_error) module for details.
 
 use core::fmt::Display;
 
@@ -11,14 +14,68 @@ use serde::ser::{
 };
 
 use crate::Either::{self, Left, Right};
+use crate::serde_error::EitherError;
 
-impl<L, R> Error for Either<L, R>
+impl<L, R> Error for EitherError<L, R>
 where
     L: Error,
     R: Error,
 {
     fn custom<T: Display>(msg: T) -> Self {
-        Left(L::custom(msg))
+        EitherError::Both(L::custom(&msg), R::custom(&msg))
+    }
+}
+
+impl<L, R> Either<L, R>
+where
+    L: Serializer,
+    R: Serializer,
+{
+    /// Serializes `value` with the contained serializer, returning the error
+    /// on the side of the contained serializer.
+    ///
+    /// Different from calling [`Serialize::serialize`] directly, the error
+    /// constructed by the [`Serialize`] implementation is attributed to the
+    /// active side, instead of being returned as [`EitherError::Both`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of serializing `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{self, Left, Right};
+    /// use serde::ser::{Error, Serialize, Serializer};
+    ///
+    /// struct Secret;
+    ///
+    /// impl Serialize for Secret {
+    ///     fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+    ///         Err(S::Error::custom("secrets are not serializable"))
+    ///     }
+    /// }
+    ///
+    /// use serde_json::value::Serializer as Json;
+    ///
+    /// let ser: Either<Json, Json> = Left(Json);
+    /// assert!(matches!(ser.serialize_attributed(&Secret), Err(Left(_))));
+    ///
+    /// let ser: Either<Json, Json> = Right(Json);
+    /// assert!(matches!(ser.serialize_attributed(&Secret), Err(Right(_))));
+    ///
+    /// let ser: Either<Json, Json> = Right(Json);
+    /// assert_eq!(ser.serialize_attributed(&1).unwrap(), Right(serde_json::json!(1)));
+    /// ```
+    pub fn serialize_attributed<T>(
+        self,
+        value: &T,
+    ) -> Result<<Self as Serializer>::Ok, Either<L::Error, R::Error>>
+    where
+        T: ?Sized + Serialize,
+    {
+        let is_left = self.is_left();
+        value.serialize(self).map_err(|e| e.attribute(is_left))
     }
 }
 
@@ -28,7 +85,7 @@ where
     R: Serializer,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
     type SerializeSeq = Either<L::SerializeSeq, R::SerializeSeq>;
     type SerializeTuple = Either<L::SerializeTuple, R::SerializeTuple>;
     type SerializeTupleStruct = Either<L::SerializeTupleStruct, R::SerializeTupleStruct>;
@@ -42,6 +99,7 @@ where
             Left(x) => Left(x.serialize_bool(v)),
             Right(x) => Right(x.serialize_bool(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
@@ -49,6 +107,7 @@ where
             Left(x) => Left(x.serialize_i8(v)),
             Right(x) => Right(x.serialize_i8(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
@@ -56,6 +115,7 @@ where
             Left(x) => Left(x.serialize_i16(v)),
             Right(x) => Right(x.serialize_i16(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
@@ -63,6 +123,7 @@ where
             Left(x) => Left(x.serialize_i32(v)),
             Right(x) => Right(x.serialize_i32(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
@@ -70,6 +131,7 @@ where
             Left(x) => Left(x.serialize_i64(v)),
             Right(x) => Right(x.serialize_i64(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
@@ -77,6 +139,7 @@ where
             Left(x) => Left(x.serialize_i128(v)),
             Right(x) => Right(x.serialize_i128(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
@@ -84,6 +147,7 @@ where
             Left(x) => Left(x.serialize_u8(v)),
             Right(x) => Right(x.serialize_u8(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
@@ -91,6 +155,7 @@ where
             Left(x) => Left(x.serialize_u16(v)),
             Right(x) => Right(x.serialize_u16(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
@@ -98,6 +163,7 @@ where
             Left(x) => Left(x.serialize_u32(v)),
             Right(x) => Right(x.serialize_u32(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
@@ -105,6 +171,7 @@ where
             Left(x) => Left(x.serialize_u64(v)),
             Right(x) => Right(x.serialize_u64(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
@@ -112,6 +179,7 @@ where
             Left(x) => Left(x.serialize_u128(v)),
             Right(x) => Right(x.serialize_u128(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
@@ -119,6 +187,7 @@ where
             Left(x) => Left(x.serialize_f32(v)),
             Right(x) => Right(x.serialize_f32(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
@@ -126,6 +195,7 @@ where
             Left(x) => Left(x.serialize_f64(v)),
             Right(x) => Right(x.serialize_f64(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
@@ -133,6 +203,7 @@ where
             Left(x) => Left(x.serialize_char(v)),
             Right(x) => Right(x.serialize_char(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
@@ -140,6 +211,7 @@ where
             Left(x) => Left(x.serialize_str(v)),
             Right(x) => Right(x.serialize_str(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
@@ -147,6 +219,7 @@ where
             Left(x) => Left(x.serialize_bytes(v)),
             Right(x) => Right(x.serialize_bytes(v)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
@@ -154,6 +227,7 @@ where
             Left(x) => Left(x.serialize_none()),
             Right(x) => Right(x.serialize_none()),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
@@ -164,6 +238,7 @@ where
             Left(x) => Left(x.serialize_some(value)),
             Right(x) => Right(x.serialize_some(value)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
@@ -171,6 +246,7 @@ where
             Left(x) => Left(x.serialize_unit()),
             Right(x) => Right(x.serialize_unit()),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
@@ -178,6 +254,7 @@ where
             Left(x) => Left(x.serialize_unit_struct(name)),
             Right(x) => Right(x.serialize_unit_struct(name)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_unit_variant(
@@ -190,6 +267,7 @@ where
             Left(x) => Left(x.serialize_unit_variant(name, variant_index, variant)),
             Right(x) => Right(x.serialize_unit_variant(name, variant_index, variant)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_newtype_struct<T>(
@@ -204,6 +282,7 @@ where
             Left(x) => Left(x.serialize_newtype_struct(name, value)),
             Right(x) => Right(x.serialize_newtype_struct(name, value)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_newtype_variant<T>(
@@ -220,6 +299,7 @@ where
             Left(x) => Left(x.serialize_newtype_variant(name, variant_index, variant, value)),
             Right(x) => Right(x.serialize_newtype_variant(name, variant_index, variant, value)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
@@ -227,6 +307,7 @@ where
             Left(x) => Left(x.serialize_seq(len)),
             Right(x) => Right(x.serialize_seq(len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
@@ -234,6 +315,7 @@ where
             Left(x) => Left(x.serialize_tuple(len)),
             Right(x) => Right(x.serialize_tuple(len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_tuple_struct(
@@ -245,6 +327,7 @@ where
             Left(x) => Left(x.serialize_tuple_struct(name, len)),
             Right(x) => Right(x.serialize_tuple_struct(name, len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_tuple_variant(
@@ -258,6 +341,7 @@ where
             Left(x) => Left(x.serialize_tuple_variant(name, variant_index, variant, len)),
             Right(x) => Right(x.serialize_tuple_variant(name, variant_index, variant, len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
@@ -265,6 +349,7 @@ where
             Left(x) => Left(x.serialize_map(len)),
             Right(x) => Right(x.serialize_map(len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_struct(
@@ -276,6 +361,7 @@ where
             Left(x) => Left(x.serialize_struct(name, len)),
             Right(x) => Right(x.serialize_struct(name, len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn serialize_struct_variant(
@@ -289,6 +375,7 @@ where
             Left(x) => Left(x.serialize_struct_variant(name, variant_index, variant, len)),
             Right(x) => Right(x.serialize_struct_variant(name, variant_index, variant, len)),
         })
+        .map_err(EitherError::from)
     }
 
     fn collect_seq<I>(self, iter: I) -> Result<Self::Ok, Self::Error>
@@ -300,6 +387,7 @@ where
             Left(x) => Left(x.collect_seq(iter)),
             Right(x) => Right(x.collect_seq(iter)),
         })
+        .map_err(EitherError::from)
     }
 
     fn collect_map<K, V, I>(self, iter: I) -> Result<Self::Ok, Self::Error>
@@ -312,6 +400,7 @@ where
             Left(x) => Left(x.collect_map(iter)),
             Right(x) => Right(x.collect_map(iter)),
         })
+        .map_err(EitherError::from)
     }
 
     fn collect_str<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
@@ -322,13 +411,11 @@ where
             Left(x) => Left(x.collect_str(value)),
             Right(x) => Right(x.collect_str(value)),
         })
+        .map_err(EitherError::from)
     }
 
     fn is_human_readable(&self) -> bool {
-        match self {
-            Left(x) => x.is_human_readable(),
-            Right(x) => x.is_human_readable(),
-        }
+        for_both!(self, x => x.is_human_readable())
     }
 }
 
@@ -338,15 +425,15 @@ where
     R: SerializeSeq,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_element(value).map_err(Left),
-            Right(x) => x.serialize_element(value).map_err(Right),
+            Left(x) => x.serialize_element(value).map_err(EitherError::Left),
+            Right(x) => x.serialize_element(value).map_err(EitherError::Right),
         }
     }
 
@@ -355,6 +442,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -364,15 +452,15 @@ where
     R: SerializeTuple,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_element(value).map_err(Left),
-            Right(x) => x.serialize_element(value).map_err(Right),
+            Left(x) => x.serialize_element(value).map_err(EitherError::Left),
+            Right(x) => x.serialize_element(value).map_err(EitherError::Right),
         }
     }
 
@@ -381,6 +469,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -390,15 +479,15 @@ where
     R: SerializeTupleStruct,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_field(value).map_err(Left),
-            Right(x) => x.serialize_field(value).map_err(Right),
+            Left(x) => x.serialize_field(value).map_err(EitherError::Left),
+            Right(x) => x.serialize_field(value).map_err(EitherError::Right),
         }
     }
 
@@ -407,6 +496,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -416,15 +506,15 @@ where
     R: SerializeTupleVariant,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_field(value).map_err(Left),
-            Right(x) => x.serialize_field(value).map_err(Right),
+            Left(x) => x.serialize_field(value).map_err(EitherError::Left),
+            Right(x) => x.serialize_field(value).map_err(EitherError::Right),
         }
     }
 
@@ -433,6 +523,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -442,15 +533,15 @@ where
     R: SerializeMap,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_key(key).map_err(Left),
-            Right(x) => x.serialize_key(key).map_err(Right),
+            Left(x) => x.serialize_key(key).map_err(EitherError::Left),
+            Right(x) => x.serialize_key(key).map_err(EitherError::Right),
         }
     }
 
@@ -459,8 +550,8 @@ where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_value(value).map_err(Left),
-            Right(x) => x.serialize_value(value).map_err(Right),
+            Left(x) => x.serialize_value(value).map_err(EitherError::Left),
+            Right(x) => x.serialize_value(value).map_err(EitherError::Right),
         }
     }
 
@@ -470,8 +561,8 @@ where
         V: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_entry(key, value).map_err(Left),
-            Right(x) => x.serialize_entry(key, value).map_err(Right),
+            Left(x) => x.serialize_entry(key, value).map_err(EitherError::Left),
+            Right(x) => x.serialize_entry(key, value).map_err(EitherError::Right),
         }
     }
 
@@ -480,6 +571,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -489,22 +581,22 @@ where
     R: SerializeStruct,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_field(key, value).map_err(Left),
-            Right(x) => x.serialize_field(key, value).map_err(Right),
+            Left(x) => x.serialize_field(key, value).map_err(EitherError::Left),
+            Right(x) => x.serialize_field(key, value).map_err(EitherError::Right),
         }
     }
 
     fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
         match self {
-            Left(x) => x.skip_field(key).map_err(Left),
-            Right(x) => x.skip_field(key).map_err(Right),
+            Left(x) => x.skip_field(key).map_err(EitherError::Left),
+            Right(x) => x.skip_field(key).map_err(EitherError::Right),
         }
     }
 
@@ -513,6 +605,7 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
 
@@ -522,22 +615,22 @@ where
     R: SerializeStructVariant,
 {
     type Ok = Either<L::Ok, R::Ok>;
-    type Error = Either<L::Error, R::Error>;
+    type Error = EitherError<L::Error, R::Error>;
 
     fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         match self {
-            Left(x) => x.serialize_field(key, value).map_err(Left),
-            Right(x) => x.serialize_field(key, value).map_err(Right),
+            Left(x) => x.serialize_field(key, value).map_err(EitherError::Left),
+            Right(x) => x.serialize_field(key, value).map_err(EitherError::Right),
         }
     }
 
     fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
         match self {
-            Left(x) => x.skip_field(key).map_err(Left),
-            Right(x) => x.skip_field(key).map_err(Right),
+            Left(x) => x.skip_field(key).map_err(EitherError::Left),
+            Right(x) => x.skip_field(key).map_err(EitherError::Right),
         }
     }
 
@@ -546,5 +639,6 @@ where
             Left(x) => Left(x.end()),
             Right(x) => Right(x.end()),
         })
+        .map_err(EitherError::from)
     }
 }
@@ -0,0 +1,164 @@
+//! This module defines [`EitherError`], the error type of the [`Serializer`]
+//! and [`Deserializer`] implementations for [`Either`].
+//!
+//! The error of the contained serializer or deserializer is returned on the
+//! same side, i.e. [`EitherError::Left`] or [`EitherError::Right`].
+//!
+//! However, the constructors of the [`ser::Error`] and [`de::Error`] traits,
+//! e.g. [`custom`], are called by the [`Serialize`] and [`Deserialize`]
+//! implementations without knowing which side is active. Instead of guessing
+//! a side, they construct the error on both sides as [`EitherError::Both`],
+//! which is attributed to the active side later by [`attribute`].
+//!
+//! The [`Either::serialize_attributed`] and [`Either::deserialize_attributed`]
+//! methods do so automatically, returning `Either<L::Error, R::Error>`.
+//!
+//! [`Serializer`]: serde::Serializer
+//! [`Deserializer`]: serde::Deserializer
+//! [`Serialize`]: serde::Serialize
+//! [`Deserialize`]: serde::Deserialize
+//! [`ser::Error`]: serde::ser::Error
+//! [`de::Error`]: serde::de::Error
+//! [`custom`]: serde::ser::Error::custom
+//! [`attribute`]: EitherError::attribute
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use either::serde_error::EitherError;
+//! use serde::de::value::{Error as ValueError, StrDeserializer};
+//! use serde::de::{Deserialize, Deserializer, Error, IntoDeserializer};
+//!
+//! #[derive(Debug)]
+//! struct Even(u32);
+//!
+//! impl<'de> Deserialize<'de> for Even {
+//!     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+//!         match u32::deserialize(deserializer)? {
+//!             x if x % 2 == 0 => Ok(Even(x)),
+//!             x => Err(D::Error::custom(format_args!("{x} is odd"))),
+//!         }
+//!     }
+//! }
+//!
+//! type Json<'a> = serde_json::Deserializer<serde_json::de::StrRead<'a>>;
+//! type Text<'a> = StrDeserializer<'a, ValueError>;
+//!
+//! // The error of the contained deserializer is on the active side.
+//! let text: Either<&mut Json, Text> = Right("text".into_deserializer());
+//! assert!(matches!(Even::deserialize(text), Err(EitherError::Right(_))));
+//!
+//! // The error constructed by the `Deserialize` implementation is on both sides...
+//! let mut json = Json::from_str("3");
+//! let json: Either<&mut Json, Text> = Left(&mut json);
+//! let err = Even::deserialize(json).unwrap_err();
+//! assert!(matches!(err, EitherError::Both(..)));
+//! assert!(matches!(err.attribute(true), Left(_)));
+//!
+//! // ...until it is attributed to the active side.
+//! let mut json = Json::from_str("5");
+//! let json: Either<&mut Json, Text> = Left(&mut json);
+//! match json.deserialize_attributed::<Even>() {
+//!     Err(Left(err)) => assert!(err.to_string().starts_with("5 is odd")),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+use crate::Either::{self, Left, Right};
+
+/// The error type of the [`Serializer`] and [`Deserializer`] implementations
+/// for [`Either`].
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// [`Serializer`]: serde::Serializer
+/// [`Deserializer`]: serde::Deserializer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EitherError<L, R> {
+    /// The error of the left serializer or deserializer.
+    Left(L),
+    /// The error of the right serializer or deserializer.
+    Right(R),
+    /// The error constructed on both sides, since the active side is unknown
+    /// to the error constructors.
+    Both(L, R),
+}
+
+impl<L, R> EitherError<L, R> {
+    /// Attributes the error to the left side if `is_left` is `true`, otherwise
+    /// to the right side.
+    ///
+    /// The errors already on one side are returned as they are.
+    ///
+    /// # Result
+    ///
+    /// | Input         | `is_left` | Output     |
+    /// | ------------- | --------- | ---------- |
+    /// | `Left(x)`     | any       | `Left(x)`  |
+    /// | `Right(y)`    | any       | `Right(y)` |
+    /// | `Both(x, _)`  | `true`    | `Left(x)`  |
+    /// | `Both(_, y)`  | `false`   | `Right(y)` |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use either::Either::{Left, Right};
+    /// # use either::serde_error::EitherError;
+    /// let err: EitherError<i32, &str> = EitherError::Both(1, "one");
+    /// assert_eq!(err.clone().attribute(true), Left(1));
+    /// assert_eq!(err.attribute(false), Right("one"));
+    ///
+    /// let err: EitherError<i32, &str> = EitherError::Right("two");
+    /// assert_eq!(err.attribute(true), Right("two"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn attribute(self, is_left: bool) -> Either<L, R> {
+        match self {
+            Self::Left(x) => Left(x),
+            Self::Right(x) => Right(x),
+            Self::Both(x, _) if is_left => Left(x),
+            Self::Both(_, x) => Right(x),
+        }
+    }
+}
+
+impl<L, R> From<Either<L, R>> for EitherError<L, R> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        match value {
+            Left(x) => Self::Left(x),
+            Right(x) => Self::Right(x),
+        }
+    }
+}
+
+impl<L, R> Display for EitherError<L, R>
+where
+    L: Display,
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(x) | Self::Both(x, _) => x.fmt(f),
+            Self::Right(x) => x.fmt(f),
+        }
+    }
+}
+
+impl<L, R> Error for EitherError<L, R>
+where
+    L: Error,
+    R: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Left(x) | Self::Both(x, _) => x.source(),
+            Self::Right(x) => x.source(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! This module implements the externally tagged serde representation for
+//! [`Either`] with lowercase variant names, to be used as
+//! `#[serde(with = "either::serde_lowercase")]`.
+//!
+//! This is the same as the derived representation, except that the variant
+//! names are `"left"` and `"right"`, which is common in configuration files.
+//!
+//! | Value          | JSON              |
+//! | -------------- | ----------------- |
+//! | `Left(1)`      | `{"left":1}`      |
+//! | `Right("one")` | `{"right":"one"}` |
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "either::serde_lowercase")]
+//!     port: Either<u16, String>,
+//! }
+//!
+//! let config = Config { port: Left(8080) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":{"left":8080}}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! let config = Config { port: Right(String::from("http")) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":{"right":"http"}}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! assert!(serde_json::from_str::<Config>(r#"{"port":{"Left":8080}}"#).is_err());
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Either::{self, Left, Right};
+
+#[derive(serde::Serialize)]
+#[serde(rename = "Either", rename_all = "lowercase")]
+enum LowercaseRef<'a, L, R> {
+    Left(&'a L),
+    Right(&'a R),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "Either", rename_all = "lowercase")]
+enum Lowercase<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Serializes `this` as a map from the lowercase variant name to the
+/// contained value.
+///
+/// # Errors
+///
+/// Returns the error of serializing the contained value.
+pub fn serialize<L, R, S>(this: &Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    L: Serialize,
+    R: Serialize,
+    S: Serializer,
+{
+    match this {
+        Left(x) => LowercaseRef::Left::<L, R>(x),
+        Right(x) => LowercaseRef::Right(x),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a map from the lowercase variant name to the contained value.
+///
+/// # Errors
+///
+/// Returns an error if the variant name is neither `"left"` nor `"right"`, or
+/// if the value does not match the type of the variant.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Either<L, R>, D::Error>
+where
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Lowercase::deserialize(deserializer).map(|x| match x {
+        Lowercase::Left(x) => Left(x),
+        Lowercase::Right(x) => Right(x),
+    })
+}
@@ -0,0 +1,210 @@
+//! This module implements the adjacently tagged serde representation for
+//! [`Either`], to be used as `#[serde(with = "either::serde_tagged")]`.
+//!
+//! The variant name is stored under the `"type"` key, and the contained value
+//! under the `"value"` key, which is the same as deriving with
+//! `#[serde(tag = "type", content = "value")]`.
+//!
+//! | Value          | JSON                                 |
+//! | -------------- | ------------------------------------ |
+//! | `Left(1)`      | `{"type":"Left","value":1}`          |
+//! | `Right("one")` | `{"type":"Right","value":"one"}`     |
+//!
+//! Since `#[serde(with = ...)]` can not pass arguments to the adapter, the
+//! [`serde_tagged!`](crate::serde_tagged!) macro generates an adapter module
+//! with other keys.
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "either::serde_tagged")]
+//!     port: Either<u16, String>,
+//! }
+//!
+//! let config = Config { port: Left(8080) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":{"type":"Left","value":8080}}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! let config = Config { port: Right(String::from("http")) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":{"type":"Right","value":"http"}}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! // The keys may appear in any order.
+//! let json = r#"{"port":{"value":8080,"type":"Left"}}"#;
+//! assert_eq!(serde_json::from_str::<Config>(json).unwrap().port, Left(8080));
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Either::{self, Left, Right};
+
+#[doc(hidden)]
+pub use serde as __serde;
+
+/// Generates a module named `$name` with the adjacently tagged adapter for
+/// [`Either`], which stores the variant name under the `$tag` key and the
+/// contained value under the `$content` key.
+///
+/// The module provides the same `serialize` and `deserialize` functions as
+/// the [`serde_tagged`](mod@crate::serde_tagged) module, to be used as
+/// `#[serde(with = "$name")]`. The name may be preceded by attributes and a
+/// visibility, e.g. `pub`.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// # use serde::{Deserialize, Serialize};
+/// either::serde_tagged! {
+///     /// The adapter with the `"kind"` and `"data"` keys.
+///     pub kind_data, "kind", "data"
+/// }
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "kind_data")]
+///     port: Either<u16, String>,
+/// }
+///
+/// let config = Config { port: Left(8080) };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"port":{"kind":"Left","data":8080}}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+///
+/// let config = Config { port: Right(String::from("http")) };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"port":{"kind":"Right","data":"http"}}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+///
+/// // The default keys are not accepted.
+/// let json = r#"{"port":{"type":"Left","value":8080}}"#;
+/// assert!(serde_json::from_str::<Config>(json).is_err());
+/// ```
+#[macro_export]
+macro_rules! serde_tagged {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $tag:literal, $content:literal $(,)?) => {
+        $(#[$attr])*
+        $vis mod $name {
+            use $crate::serde_tagged::__serde as serde;
+            use serde::de::{Deserialize, Deserializer};
+            use serde::ser::{Serialize, Serializer};
+
+            #[derive(serde::Serialize)]
+            #[serde(crate = "self::serde", rename = "Either", tag = $tag, content = $content)]
+            enum TaggedRef<'a, L, R> {
+                Left(&'a L),
+                Right(&'a R),
+            }
+
+            #[derive(serde::Deserialize)]
+            #[serde(crate = "self::serde", rename = "Either", tag = $tag, content = $content)]
+            enum Tagged<L, R> {
+                Left(L),
+                Right(R),
+            }
+
+            #[doc = concat!(
+                "Serializes `this` as a map with the variant name under the `\"",
+                $tag,
+                "\"` key and the contained value under the `\"",
+                $content,
+                "\"` key.",
+            )]
+            pub fn serialize<L, R, S>(
+                this: &$crate::Either<L, R>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                L: Serialize,
+                R: Serialize,
+                S: Serializer,
+            {
+                match this {
+                    $crate::Either::Left(x) => TaggedRef::Left::<L, R>(x),
+                    $crate::Either::Right(x) => TaggedRef::Right(x),
+                }
+                .serialize(serializer)
+            }
+
+            #[doc = concat!(
+                "Deserializes a map with the variant name under the `\"",
+                $tag,
+                "\"` key and the contained value under the `\"",
+                $content,
+                "\"` key.",
+            )]
+            pub fn deserialize<'de, L, R, D>(
+                deserializer: D,
+            ) -> Result<$crate::Either<L, R>, D::Error>
+            where
+                L: Deserialize<'de>,
+                R: Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                Tagged::deserialize(deserializer).map(|x| match x {
+                    Tagged::Left(x) => $crate::Either::Left(x),
+                    Tagged::Right(x) => $crate::Either::Right(x),
+                })
+            }
+        }
+    };
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename = "Either", tag = "type", content = "value")]
+enum TaggedRef<'a, L, R> {
+    Left(&'a L),
+    Right(&'a R),
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "Either", tag = "type", content = "value")]
+enum Tagged<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Serializes `this` as a map with the variant name under the `"type"` key
+/// and the contained value under the `"value"` key.
+///
+/// # Errors
+///
+/// Returns the error of serializing the contained value.
+pub fn serialize<L, R, S>(this: &Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    L: Serialize,
+    R: Serialize,
+    S: Serializer,
+{
+    match this {
+        Left(x) => TaggedRef::Left::<L, R>(x),
+        Right(x) => TaggedRef::Right(x),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a map with the variant name under the `"type"` key and the
+/// contained value under the `"value"` key.
+///
+/// # Errors
+///
+/// Returns an error if either key is missing, or if the value does not match
+/// the type of the tagged variant.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Either<L, R>, D::Error>
+where
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Tagged::deserialize(deserializer).map(|x| match x {
+        Tagged::Left(x) => Left(x),
+        Tagged::Right(x) => Right(x),
+    })
+}
@@ -0,0 +1,352 @@
+//! This module implements the untagged serde representation for [`Either`],
+//! to be used as `#[serde(with = "either::serde_untagged")]`.
+//!
+//! The contained value is serialized directly, without any tag. On
+//! deserializing, the input is buffered and then tried as `L` first, and as
+//! `R` if that fails. When neither matches, the error lists both failures.
+//!
+//! | Value          | JSON     |
+//! | -------------- | -------- |
+//! | `Left(1)`      | `1`      |
+//! | `Right("one")` | `"one"`  |
+//!
+//! Note that the representation is ambiguous when a value can be deserialized
+//! as both `L` and `R`, in which case it is always deserialized as [`Left`].
+//!
+//! [`Left`]: crate::Either::Left
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "either::serde_untagged")]
+//!     port: Either<u16, String>,
+//! }
+//!
+//! let config = Config { port: Left(8080) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":8080}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! let config = Config { port: Right(String::from("http")) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"port":"http"}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//!
+//! let error = serde_json::from_str::<Config>(r#"{"port":true}"#).unwrap_err();
+//! assert!(error.to_string().contains("expected u16"));
+//! assert!(error.to_string().contains("expected a string"));
+//! ```
+
+use core::fmt::{self, Formatter};
+
+use serde::de::value::{
+    Error as ValueError, MapAccessDeserializer, MapDeserializer, SeqDeserializer,
+};
+use serde::de::{
+    Deserialize, Deserializer, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Either::{self, Left, Right};
+
+/// Serializes the contained value of `this` without any tag.
+///
+/// # Errors
+///
+/// Returns the error of serializing the contained value.
+pub fn serialize<L, R, S>(this: &Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    L: Serialize,
+    R: Serialize,
+    S: Serializer,
+{
+    for_both!(this, x => x.serialize(serializer))
+}
+
+/// Deserializes `L` if the input matches, otherwise deserializes `R`.
+///
+/// # Errors
+///
+/// Returns an error if the input can not be buffered, or if it matches
+/// neither `L` nor `R`. In the latter case, the error message contains both
+/// failures.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Either<L, R>, D::Error>
+where
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let human_readable = deserializer.is_human_readable();
+    let content = Content::deserialize(deserializer)?;
+    let replay = || ContentRef {
+        content: &content,
+        human_readable,
+    };
+
+    let left = match L::deserialize(replay()) {
+        Ok(x) => return Ok(Left(x)),
+        Err(e) => e,
+    };
+    let right = match R::deserialize(replay()) {
+        Ok(x) => return Ok(Right(x)),
+        Err(e) => e,
+    };
+    Err(D::Error::custom(format_args!(
+        "data did not match either side of untagged `Either`: left: {left}; right: {right}"
+    )))
+}
+
+/// The wrapper of a borrowed [`Either`] serialized in the untagged
+/// representation.
+pub(crate) struct UntaggedRef<'a, L, R>(pub(crate) &'a Either<L, R>);
+
+impl<L: Serialize, R: Serialize> Serialize for UntaggedRef<'_, L, R> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+/// The wrapper of an [`Either`] deserialized in the untagged representation.
+pub(crate) struct Untagged<L, R>(pub(crate) Either<L, R>);
+
+impl<'de, L: Deserialize<'de>, R: Deserialize<'de>> Deserialize<'de> for Untagged<L, R> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Untagged)
+    }
+}
+
+/// The buffered self-describing input, replayed once for each side.
+enum Content<'de> {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    F64(f64),
+    Char(char),
+    Str(&'de str),
+    String(String),
+    Bytes(&'de [u8]),
+    ByteBuf(Vec<u8>),
+    None,
+    Some(Box<Content<'de>>),
+    Unit,
+    Newtype(Box<Content<'de>>),
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(v.into()))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Content::Str(v))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v.into()))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Content::ByteBuf(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        Content::deserialize(deserializer).map(|x| Content::Some(Box::new(x)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        Content::deserialize(deserializer).map(|x| Content::Newtype(Box::new(x)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(x) = seq.next_element()? {
+            vec.push(x);
+        }
+        Ok(Content::Seq(vec))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut vec = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+        while let Some(x) = map.next_entry()? {
+            vec.push(x);
+        }
+        Ok(Content::Map(vec))
+    }
+}
+
+/// The deserializer replaying a borrowed [`Content`].
+#[derive(Clone, Copy)]
+struct ContentRef<'a, 'de> {
+    content: &'a Content<'de>,
+    human_readable: bool,
+}
+
+impl<'a, 'de> ContentRef<'a, 'de> {
+    #[inline]
+    fn with(self, content: &'a Content<'de>) -> Self {
+        Self { content, ..self }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ValueError> for ContentRef<'_, 'de> {
+    type Deserializer = Self;
+
+    #[inline]
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for ContentRef<'_, 'de> {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(*v),
+            Content::I64(v) => visitor.visit_i64(*v),
+            Content::U64(v) => visitor.visit_u64(*v),
+            Content::I128(v) => visitor.visit_i128(*v),
+            Content::U128(v) => visitor.visit_u128(*v),
+            Content::F64(v) => visitor.visit_f64(*v),
+            Content::Char(v) => visitor.visit_char(*v),
+            Content::Str(v) => visitor.visit_borrowed_str(v),
+            Content::String(v) => visitor.visit_str(v),
+            Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+            Content::ByteBuf(v) => visitor.visit_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.with(v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.with(v)),
+            Content::Seq(v) => {
+                let mut seq = SeqDeserializer::new(v.iter().map(|x| self.with(x)));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end().map(|()| value)
+            }
+            Content::Map(v) => {
+                let iter = v.iter().map(|(k, v)| (self.with(k), self.with(v)));
+                let mut map = MapDeserializer::new(iter);
+                let value = visitor.visit_map(&mut map)?;
+                map.end().map(|()| value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.with(v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.with(v)),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.content {
+            Content::Str(v) => visitor.visit_enum(v.into_deserializer()),
+            Content::String(v) => visitor.visit_enum(v.as_str().into_deserializer()),
+            Content::Map(v) if v.len() == 1 => {
+                let iter = v.iter().map(|(k, v)| (self.with(k), self.with(v)));
+                visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(iter)))
+            }
+            _ => Err(ValueError::custom(
+                "expected a string or a map with a single key for an enum",
+            )),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
@@ -0,0 +1,78 @@
+//! This module implements the untagged serde representation for
+//! `Option<Either>`, to be used as
+//! `#[serde(with = "either::serde_untagged_optional")]`.
+//!
+//! [`None`] is serialized as the unit-like none value (`null` in JSON), and
+//! [`Some`] is serialized as the contained value of the [`Either`], in the
+//! same way as the [`serde_untagged`](crate::serde_untagged) module.
+//!
+//! | Value                | JSON    |
+//! | -------------------- | ------- |
+//! | `None`               | `null`  |
+//! | `Some(Left(1))`      | `1`     |
+//! | `Some(Right("one"))` | `"one"` |
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(default, with = "either::serde_untagged_optional")]
+//!     port: Option<Either<u16, String>>,
+//! }
+//!
+//! for config in [
+//!     Config { port: None },
+//!     Config { port: Some(Left(8080)) },
+//!     Config { port: Some(Right(String::from("http"))) },
+//! ] {
+//!     let json = serde_json::to_string(&config).unwrap();
+//!     assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//! }
+//!
+//! let config = Config { port: Some(Left(8080)) };
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"port":8080}"#);
+//! assert_eq!(serde_json::from_str::<Config>("{}").unwrap(), Config { port: None });
+//! assert_eq!(serde_json::from_str::<Config>(r#"{"port":null}"#).unwrap(), Config { port: None });
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Either;
+use crate::serde_untagged::{Untagged, UntaggedRef};
+
+/// Serializes [`None`] as none, and the contained value of [`Some`] without
+/// any tag.
+///
+/// # Errors
+///
+/// Returns the error of serializing the contained value.
+pub fn serialize<L, R, S>(this: &Option<Either<L, R>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    L: Serialize,
+    R: Serialize,
+    S: Serializer,
+{
+    match this {
+        Some(x) => serializer.serialize_some(&UntaggedRef(x)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes [`None`] from none, otherwise deserializes [`Some`] as
+/// [`serde_untagged::deserialize`](crate::serde_untagged::deserialize) does.
+///
+/// # Errors
+///
+/// Returns an error if the input is neither none, nor matches `L` or `R`.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Option<Either<L, R>>, D::Error>
+where
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<Untagged<L, R>>::deserialize(deserializer).map(|x| x.map(|x| x.0))
+}
@@ -0,0 +1,100 @@
+//! This module implements the [`Stream`], [`FusedStream`] and [`Sink`] traits
+//! for [`Either`], if and only if, both `L` and `R` implement the
+//! corresponding trait.
+//!
+//! The contained value is projected with [`Either::as_pin_mut`], so `L` and
+//! `R` are not required to implement [`Unpin`].
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left};
+//! use futures::executor::block_on;
+//! use futures::sink::{self, SinkExt};
+//! use futures::stream::{self, Stream, StreamExt};
+//!
+//! let x: Either<_, stream::Empty<i32>> = Left(stream::iter([1, 2, 3]));
+//! assert_eq!(x.size_hint(), (3, Some(3)));
+//! assert_eq!(block_on(x.collect::<Vec<_>>()), [1, 2, 3]);
+//!
+//! let mut y: Either<Vec<i32>, sink::Drain<i32>> = Left(Vec::new());
+//! block_on(y.send(4)).unwrap();
+//! assert_eq!(y.left(), Some(vec![4]));
+//! ```
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::{FusedStream, Stream};
+use futures_sink::Sink;
+
+use crate::Either::{self, Left, Right};
+
+impl<L, R> Stream for Either<L, R>
+where
+    L: Stream,
+    R: Stream<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        for_both!(self.as_pin_mut(), x => x.poll_next(cx))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        for_both!(self, x => x.size_hint())
+    }
+}
+
+impl<L, R> FusedStream for Either<L, R>
+where
+    L: FusedStream,
+    R: FusedStream<Item = L::Item>,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        for_both!(self, x => x.is_terminated())
+    }
+}
+
+impl<L, R, Item> Sink<Item> for Either<L, R>
+where
+    L: Sink<Item>,
+    R: Sink<Item>,
+{
+    type Error = Either<L::Error, R::Error>;
+
+    #[inline]
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            Left(x) => x.poll_ready(cx).map_err(Left),
+            Right(x) => x.poll_ready(cx).map_err(Right),
+        }
+    }
+
+    #[inline]
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        match self.as_pin_mut() {
+            Left(x) => x.start_send(item).map_err(Left),
+            Right(x) => x.start_send(item).map_err(Right),
+        }
+    }
+
+    #[inline]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            Left(x) => x.poll_flush(cx).map_err(Left),
+            Right(x) => x.poll_flush(cx).map_err(Right),
+        }
+    }
+
+    #[inline]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            Left(x) => x.poll_close(cx).map_err(Left),
+            Right(x) => x.poll_close(cx).map_err(Right),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! This module implements the nightly [`Try`] trait for the [`Biased`]
+//! wrapper of [`Either`], with the nightly `try_trait` feature.
+//!
+//! [`Either`] itself has no preferred variant, so `?` cannot decide which
+//! value to continue with. [`Either::left_biased`] and
+//! [`Either::right_biased`] choose the preferred variant by the type-level
+//! markers of the [`bias`](crate::bias) module: `?` evaluates to the value
+//! of the preferred variant, or propagates the value of the other variant
+//! into a function returning:
+//!
+//! - [`Either`], wrapped in the other variant and converted by [`From`];
+//! - [`Result`], wrapped in [`Err`] and converted by [`From`];
+//! - [`Option`], as [`None`];
+//! - [`Biased`] of the same bias, converted by [`From`].
+//!
+//! On stable, [`Either::left_flow`] and [`Either::right_flow`] provide the
+//! same with [`ControlFlow`].
+//!
+//! # Example
+//!
+//! ```
+//! # use either::Either::{self, Left, Right};
+//! fn double(x: Either<u32, &str>) -> Either<u32, String> {
+//!     let x = x.left_biased()?;
+//!     Left(x * 2)
+//! }
+//!
+//! assert_eq!(double(Left(21)), Left(42));
+//! assert_eq!(double(Right("nan")), Right("nan".to_owned()));
+//!
+//! fn parse(x: Either<&str, u32>) -> Result<u32, String> {
+//!     let n = x.right_biased()?;
+//!     Ok(n + 1)
+//! }
+//!
+//! assert_eq!(parse(Right(1)), Ok(2));
+//! assert_eq!(parse(Left("one")), Err("one".to_owned()));
+//!
+//! fn first(x: Either<Vec<u8>, u8>) -> Option<u8> {
+//!     x.left_biased()?.first().copied()
+//! }
+//!
+//! assert_eq!(first(Left(vec![1, 2])), Some(1));
+//! assert_eq!(first(Right(1)), None);
+//! ```
+
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+use crate::Either::{self, Left, Right};
+use crate::bias::{LeftBiased, RightBiased};
+
+/// A wrapper of [`Either`] preferring the variant selected by `S`, which is
+/// either [`LeftBiased`] or [`RightBiased`], when applying the `?` operator.
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// # Example
+///
+/// ```
+/// # use either::Either::{self, Left, Right};
+/// use either::bias::LeftBiased;
+/// use either::try_trait::Biased;
+///
+/// fn total(items: &[Either<u32, &'static str>]) -> Biased<u32, &'static str, LeftBiased> {
+///     let mut sum = 0;
+///     for &item in items {
+///         sum += item.left_biased()?;
+///     }
+///     Biased::new(Left(sum))
+/// }
+///
+/// assert_eq!(total(&[Left(1), Left(2)]).into_inner(), Left(3));
+/// assert_eq!(total(&[Left(1), Right("x")]).into_inner(), Right("x"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Biased<L, R, S>(pub Either<L, R>, PhantomData<S>);
+
+impl<L, R, S> Biased<L, R, S> {
+    /// Wraps an [`Either`].
+    #[inline]
+    #[must_use]
+    pub const fn new(value: Either<L, R>) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the wrapped [`Either`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Either<L, R> {
+        self.0
+    }
+
+    /// Returns the same value, preferring the variant selected by `T`
+    /// instead.
+    #[inline]
+    #[must_use]
+    pub fn rebias<T>(self) -> Biased<L, R, T> {
+        Biased::new(self.0)
+    }
+}
+
+impl<L, R, S> From<Either<L, R>> for Biased<L, R, S> {
+    #[inline]
+    fn from(value: Either<L, R>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<L, R> Either<L, R> {
+    /// Wraps `self` in [`Biased`], so `?` evaluates to the [`Left`] value,
+    /// or propagates the [`Right`] value.
+    ///
+    /// See the [`try_trait`](crate::try_trait) module for more details.
+    ///
+    /// [`Left`]: crate::Either::Left
+    /// [`Right`]: crate::Either::Right
+    #[inline]
+    pub fn left_biased(self) -> Biased<L, R, LeftBiased> {
+        Biased::new(self)
+    }
+
+    /// Wraps `self` in [`Biased`], so `?` evaluates to the [`Right`] value,
+    /// or propagates the [`Left`] value.
+    ///
+    /// See the [`try_trait`](crate::try_trait) module for more details.
+    ///
+    /// [`Left`]: crate::Either::Left
+    /// [`Right`]: crate::Either::Right
+    #[inline]
+    pub fn right_biased(self) -> Biased<L, R, RightBiased> {
+        Biased::new(self)
+    }
+}
+
+impl<L, R> Try for Biased<L, R, LeftBiased> {
+    type Output = L;
+    type Residual = Biased<Infallible, R, LeftBiased>;
+
+    #[inline]
+    fn from_output(output: L) -> Self {
+        Self::new(Left(output))
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, L> {
+        match self.0 {
+            Left(x) => ControlFlow::Continue(x),
+            Right(x) => ControlFlow::Break(Biased::new(Right(x))),
+        }
+    }
+}
+
+impl<L, R> Try for Biased<L, R, RightBiased> {
+    type Output = R;
+    type Residual = Biased<L, Infallible, RightBiased>;
+
+    #[inline]
+    fn from_output(output: R) -> Self {
+        Self::new(Right(output))
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, R> {
+        match self.0 {
+            Left(x) => ControlFlow::Break(Biased::new(Left(x))),
+            Right(x) => ControlFlow::Continue(x),
+        }
+    }
+}
+
+impl<L, R> Residual<L> for Biased<Infallible, R, LeftBiased> {
+    type TryType = Biased<L, R, LeftBiased>;
+}
+
+impl<L, R> Residual<R> for Biased<L, Infallible, RightBiased> {
+    type TryType = Biased<L, R, RightBiased>;
+}
+
+impl<L, R, E> FromResidual<Biased<Infallible, E, LeftBiased>> for Biased<L, R, LeftBiased>
+where
+    R: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<Infallible, E, LeftBiased>) -> Self {
+        Self::new(Right(residual.into_right()))
+    }
+}
+
+impl<L, R, E> FromResidual<Biased<E, Infallible, RightBiased>> for Biased<L, R, RightBiased>
+where
+    L: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<E, Infallible, RightBiased>) -> Self {
+        Self::new(Left(residual.into_left()))
+    }
+}
+
+impl<L, R, E> FromResidual<Biased<Infallible, E, LeftBiased>> for Either<L, R>
+where
+    R: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<Infallible, E, LeftBiased>) -> Self {
+        Right(residual.into_right())
+    }
+}
+
+impl<L, R, E> FromResidual<Biased<E, Infallible, RightBiased>> for Either<L, R>
+where
+    L: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<E, Infallible, RightBiased>) -> Self {
+        Left(residual.into_left())
+    }
+}
+
+impl<T, F, E> FromResidual<Biased<Infallible, E, LeftBiased>> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<Infallible, E, LeftBiased>) -> Self {
+        Err(residual.into_right())
+    }
+}
+
+impl<T, F, E> FromResidual<Biased<E, Infallible, RightBiased>> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Biased<E, Infallible, RightBiased>) -> Self {
+        Err(residual.into_left())
+    }
+}
+
+impl<T, E> FromResidual<Biased<Infallible, E, LeftBiased>> for Option<T> {
+    #[inline]
+    fn from_residual(_: Biased<Infallible, E, LeftBiased>) -> Self {
+        None
+    }
+}
+
+impl<T, E> FromResidual<Biased<E, Infallible, RightBiased>> for Option<T> {
+    #[inline]
+    fn from_residual(_: Biased<E, Infallible, RightBiased>) -> Self {
+        None
+    }
+}
+
+impl<E> Biased<Infallible, E, LeftBiased> {
+    /// Returns the propagated value, converted into `T`.
+    #[inline]
+    fn into_right<T: From<E>>(self) -> T {
+        match self.0 {
+            Left(x) => match x {},
+            Right(x) => T::from(x),
+        }
+    }
+}
+
+impl<E> Biased<E, Infallible, RightBiased> {
+    /// Returns the propagated value, converted into `T`.
+    #[inline]
+    fn into_left<T: From<E>>(self) -> T {
+        match self.0 {
+            Left(x) => T::from(x),
+            Right(x) => match x {},
+        }
+    }
+}
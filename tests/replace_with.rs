@@ -0,0 +1,141 @@
+//! Checks that a panic inside the closure of an in-place variant transition
+//! never drops the moved-out value twice: the value in place is replaced with
+//! the fallback value, or the process is aborted if there is none.
+
+use std::cell::Cell;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::Command;
+
+use either::Either::{self, Left, Right};
+
+/// Counts its drops, so a double drop shows in the counter.
+struct Counted<'a>(&'a Cell<usize>);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// Runs `f` on a [`Left`] value with a drop counter, which must panic, then
+/// checks that the value was dropped exactly once and `self` holds the
+/// fallback value `Right(0)`.
+fn check_left(f: impl FnOnce(&mut Either<Counted<'_>, u32>)) {
+    let drops = Cell::new(0);
+    let mut x = Left(Counted(&drops));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| f(&mut x)));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 1);
+    assert!(matches!(x, Right(0)));
+    drop(x);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn replace_with_or_else_unwinds_into_fallback() {
+    check_left(|x| x.replace_with_or_else(|| Right(0), |_| panic!("closure panicked")));
+}
+
+#[test]
+fn try_replace_with_or_else_unwinds_into_fallback() {
+    check_left(|x| {
+        let _ = x.try_replace_with_or_else(
+            || Right(0),
+            |_| -> Result<_, (_, ())> { panic!("closure panicked") },
+        );
+    });
+}
+
+#[test]
+fn left_to_right_in_place_or_else_unwinds_into_fallback() {
+    check_left(|x| {
+        x.left_to_right_in_place_or_else(|| Right(0), |_| panic!("closure panicked"));
+    });
+}
+
+#[test]
+fn right_to_left_in_place_or_else_unwinds_into_fallback() {
+    let drops = Cell::new(0);
+    let mut x: Either<u32, Counted<'_>> = Right(Counted(&drops));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        x.right_to_left_in_place_or_else(|| Left(0), |_| panic!("closure panicked"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 1);
+    assert!(matches!(x, Left(0)));
+    drop(x);
+    assert_eq!(drops.get(), 1);
+}
+
+const CASE: &str = "EITHER_REPLACE_WITH_CASE";
+
+/// Prints when dropped, so a double drop shows in the output of the child.
+struct Noisy;
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        eprintln!("dropped");
+    }
+}
+
+/// Runs the case selected by `EITHER_REPLACE_WITH_CASE` in this process,
+/// which is expected to abort.
+fn abort_case(case: &str) {
+    let mut x: Either<Noisy, Noisy> = Left(Noisy);
+    match case {
+        "replace-with" => x.replace_with(|_| panic!("closure panicked")),
+        "left-and-modify" => {
+            x.left_and_modify(|_| panic!("closure panicked"));
+        }
+        "right-or-insert-with" => {
+            x.right_or_insert_with(|_| panic!("closure panicked"));
+        }
+        "left-to-right-in-place" => {
+            x.left_to_right_in_place(|_| panic!("closure panicked"));
+        }
+        "try-replace-with" => {
+            let _ = x.try_replace_with(|_| -> Result<_, (_, ())> { panic!("closure panicked") });
+        }
+        _ => panic!("unknown case `{case}`"),
+    }
+}
+
+/// Runs each case without a fallback value in a child process, which reruns
+/// this test only, and checks that the child was aborted, while its stderr
+/// reports the original panic.
+#[test]
+#[cfg_attr(miri, ignore = "spawns child processes")]
+fn aborts_without_fallback() {
+    if let Ok(case) = env::var(CASE) {
+        return abort_case(&case);
+    }
+
+    for case in [
+        "replace-with",
+        "left-and-modify",
+        "right-or-insert-with",
+        "left-to-right-in-place",
+        "try-replace-with",
+    ] {
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["--exact", "aborts_without_fallback", "--nocapture"])
+            .env(CASE, case)
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(!output.status.success(), "case `{case}` did not abort");
+        assert_ne!(output.status.code(), Some(101), "case `{case}` unwound");
+        assert!(
+            stderr.contains("closure panicked"),
+            "case `{case}`: {stderr}"
+        );
+        // The value moved into the closure is dropped once during unwinding,
+        // then the process is aborted before `x` could be dropped again.
+        assert_eq!(
+            stderr.matches("dropped").count(),
+            1,
+            "case `{case}`: {stderr}"
+        );
+    }
+}
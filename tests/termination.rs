@@ -0,0 +1,51 @@
+//! Checks the exit status of a process whose `main` returns an `Either`.
+//!
+//! This test has no harness: it runs itself as a child process for each case,
+//! selected by the `EITHER_TERMINATION_CASE` environment variable, and checks
+//! the exit code and the output of the child.
+
+use std::env;
+use std::process::{Command, ExitCode, Output};
+
+use either::Either::{self, Left, Right};
+
+const CASE: &str = "EITHER_TERMINATION_CASE";
+
+fn child(case: &str) -> Either<ExitCode, Result<(), String>> {
+    match case {
+        "left" => Left(ExitCode::from(3)),
+        "right-ok" => Right(Ok(())),
+        "right-err" => Right(Err("no input files".to_owned())),
+        "exit-left" => Left::<(), ()>(()).exit_with(4, 5),
+        "exit-right" => Right::<(), ()>(()).exit_with(4, 5),
+        _ => panic!("unknown case `{case}`"),
+    }
+}
+
+fn run(case: &str) -> Output {
+    Command::new(env::current_exe().unwrap())
+        .env(CASE, case)
+        .output()
+        .unwrap()
+}
+
+fn main() -> Either<ExitCode, Result<(), String>> {
+    if let Ok(case) = env::var(CASE) {
+        return child(&case);
+    }
+
+    assert_eq!(run("left").status.code(), Some(3));
+    assert_eq!(run("right-ok").status.code(), Some(0));
+
+    let output = run("right-err");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Error: \"no input files\"\n"
+    );
+
+    assert_eq!(run("exit-left").status.code(), Some(4));
+    assert_eq!(run("exit-right").status.code(), Some(5));
+
+    Right(Ok(()))
+}